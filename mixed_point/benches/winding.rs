@@ -0,0 +1,90 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use mixed_point::{
+    CycleKind, Filter, IdentityPeriodRepresentation, Mixed, Period, SimpleCycle, SimpleFilter, SimpleMixed,
+};
+use numburs::{pos, Integer};
+
+type Rep = IdentityPeriodRepresentation<i64>;
+type MixedType<const N: usize> = SimpleMixed<Rep, SimpleCycle<i64>, N>;
+type FilterType = SimpleFilter<Rep, CycleKind<i64>, 3>;
+
+// Powers of two, which wind by shifting
+const CLOCK: MixedType<5> = MixedType::<5>::from_sizes([2, 64, 64, 8, 16]);
+const DECIMAL: MixedType<3> = MixedType::<3>::from_sizes([10, 10, 10]);
+const DAYS: FilterType = FilterType::from_kinds(
+    Period::new(pos!(const i64: 93502)),
+    [
+        CycleKind::simple(pos!(const i64: 5844)),
+        CycleKind::simple(pos!(const i64: 2922)),
+        CycleKind::limited(pos!(const i64: 365), pos!(const i64: 7)),
+    ],
+);
+
+const COUNT: i64 = 100_000;
+
+fn totals() -> Vec<Integer<i64>> {
+    (0..COUNT).map(|k| Integer::new(k * 7919 - COUNT * 4000)).collect()
+}
+
+// Each total through the trait, against the batch over precomputed place values
+fn mixed<const N: usize>(c: &mut Criterion, name: &str, system: &MixedType<N>) {
+    let totals = totals();
+    let places = system.place_values();
+    let mut points = vec![system.wind_inner(&Integer::new(0)); totals.len()];
+    let mut unwound = vec![Integer::new(0); totals.len()];
+    c.bench_function(&format!("{} wind", name), |b| {
+        b.iter(|| {
+            for (total, point) in totals.iter().zip(points.iter_mut()) {
+                *point = system.wind_inner(black_box(total));
+            }
+        })
+    });
+    c.bench_function(&format!("{} wind_into", name), |b| {
+        b.iter(|| places.wind_into(black_box(&totals), &mut points))
+    });
+    c.bench_function(&format!("{} unwind", name), |b| {
+        b.iter(|| {
+            for (point, total) in points.iter().zip(unwound.iter_mut()) {
+                *total = system.unwind(black_box(point));
+            }
+        })
+    });
+    c.bench_function(&format!("{} unwind_into", name), |b| {
+        b.iter(|| places.unwind_into(black_box(&points), &mut unwound))
+    });
+}
+
+fn mixed_systems(c: &mut Criterion) {
+    mixed(c, "clock", &CLOCK);
+    mixed(c, "decimal", &DECIMAL);
+}
+
+fn filter(c: &mut Criterion) {
+    let totals = totals();
+    let places = DAYS.place_values();
+    let mut points = vec![DAYS.wind_inner(&Integer::new(0)); totals.len()];
+    let mut unwound = vec![Integer::new(0); totals.len()];
+    c.bench_function("days wind", |b| {
+        b.iter(|| {
+            for (total, point) in totals.iter().zip(points.iter_mut()) {
+                *point = DAYS.wind_inner(black_box(total));
+            }
+        })
+    });
+    c.bench_function("days wind_into", |b| {
+        b.iter(|| places.wind_into(black_box(&totals), &mut points))
+    });
+    c.bench_function("days unwind", |b| {
+        b.iter(|| {
+            for (point, total) in points.iter().zip(unwound.iter_mut()) {
+                *total = DAYS.unwind(black_box(point));
+            }
+        })
+    });
+    c.bench_function("days unwind_into", |b| {
+        b.iter(|| places.unwind_into(black_box(&points), &mut unwound))
+    });
+}
+
+criterion_group!(benches, mixed_systems, filter);
+criterion_main!(benches);
@@ -0,0 +1,331 @@
+use core::fmt::{self, Display};
+
+use numburs::{ConstrainedRep, ConstrainedSub, Integer, Natural, NumBase};
+
+use crate::{
+    cycle::{
+        cycle_kind::CycleKind,
+        limited_cycle::LimitedCycle,
+        period::{Period, PeriodRepresentation},
+        simple_cycle::SimpleCycle,
+        types::{BoundCyclePoint, Cycle, CyclePoint},
+    },
+    filter::{
+        simple::SimpleFilter,
+        types::{BoundFilterPoint, Filter, FilterPoint},
+    },
+    mixed::{
+        simple::SimpleMixed,
+        types::{BoundMixedPoint, Mixed, MixedPoint},
+    },
+    offset::Offset,
+};
+
+// Supported ranges: periods, mixed systems and filters counting in Integer<L>
+// wind every total in [L::MIN, L::MAX], and unwind exactly the points that
+// winding those totals gives. Cycles counting in Natural<N> do the same for
+// [0, N::MAX]. An offset of `o` narrows the totals that wind to
+// [L::MIN - o, L::MAX - o]. Anything else is an `OverflowError`.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum OverflowError {
+    Wind,
+    Unwind,
+}
+
+impl Display for OverflowError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            OverflowError::Wind => write!(f, "the total is out of range for winding"),
+            OverflowError::Unwind => write!(f, "the point unwinds to a total out of range"),
+        }
+    }
+}
+
+impl std::error::Error for OverflowError {}
+
+pub trait CheckedCycle<N: NumBase>: Cycle<N> {
+    fn try_wind_inner(&self, cycles: &Self::Cycles) -> Result<CyclePoint<N, Self::Cycles>, OverflowError>;
+    fn try_unwind(&self, point: &CyclePoint<N, Self::Cycles>) -> Result<Self::Cycles, OverflowError>;
+
+    fn try_wind(&self, cycles: &Self::Cycles) -> Result<BoundCyclePoint<'_, N, Self>, OverflowError> {
+        self.try_wind_inner(cycles).map(|p| BoundCyclePoint::new(self, p))
+    }
+}
+
+pub trait CheckedMixed<const N: usize>: Mixed<N> {
+    fn try_wind_inner(&self, total: &Self::Cycles) -> Result<MixedPoint<Self::U, N, Self::Cycles>, OverflowError>;
+    fn try_unwind(&self, point: &MixedPoint<Self::U, N, Self::Cycles>) -> Result<Self::Cycles, OverflowError>;
+
+    fn try_wind(&self, total: Self::Cycles) -> Result<BoundMixedPoint<'_, N, Self>, OverflowError> {
+        self.try_wind_inner(&total).map(|p| BoundMixedPoint::new(self, p))
+    }
+}
+
+pub trait CheckedFilter<const N: usize>: Filter<N> {
+    fn try_wind_inner(&self, total: &Self::Cycle) -> Result<FilterPoint<Self::U, N, Self::Cycle>, OverflowError>;
+    fn try_unwind(&self, point: &FilterPoint<Self::U, N, Self::Cycle>) -> Result<Self::Cycle, OverflowError>;
+
+    fn try_wind(&self, total: Self::Cycle) -> Result<BoundFilterPoint<'_, N, Self>, OverflowError> {
+        self.try_wind_inner(&total).map(|p| BoundFilterPoint::new(self, p))
+    }
+}
+
+// cycle * size + phase, taking a negative cycle as (cycle + 1) * size plus a
+// negative phase so that totals close to the minimum do not overflow midway
+fn checked_unwind<N: NumBase>(cycle: N, size: N, phase: N) -> Result<N, OverflowError> {
+    let (cycle, phase) = if cycle < N::ZERO {
+        (cycle + N::ONE, phase - size)
+    } else {
+        (cycle, phase)
+    };
+    cycle
+        .checked_mul(&size)
+        .and_then(|total| total.checked_add(&phase))
+        .ok_or(OverflowError::Unwind)
+}
+
+// Winding divides, so only unwinding can overflow
+impl<N: NumBase> CheckedCycle<N> for SimpleCycle<N> {
+    fn try_wind_inner(&self, cycles: &Self::Cycles) -> Result<CyclePoint<N, Self::Cycles>, OverflowError> {
+        Ok(self.wind_inner(cycles))
+    }
+
+    fn try_unwind(&self, point: &CyclePoint<N, Self::Cycles>) -> Result<Self::Cycles, OverflowError> {
+        checked_unwind(point.cycle.out(), self.size().out(), point.phase.out()).map(Natural::new_unchecked)
+    }
+}
+
+impl<N: NumBase> CheckedCycle<N> for LimitedCycle<N> {
+    fn try_wind_inner(&self, cycles: &Self::Cycles) -> Result<CyclePoint<N, Self::Cycles>, OverflowError> {
+        Ok(self.wind_inner(cycles))
+    }
+
+    fn try_unwind(&self, point: &CyclePoint<N, Self::Cycles>) -> Result<Self::Cycles, OverflowError> {
+        checked_unwind(point.cycle.out(), self.size().out(), point.phase.out()).map(Natural::new_unchecked)
+    }
+}
+
+impl<N: NumBase> CheckedCycle<N> for CycleKind<N> {
+    fn try_wind_inner(&self, cycles: &Self::Cycles) -> Result<CyclePoint<N, Self::Cycles>, OverflowError> {
+        match self {
+            Self::Simple(cycle) => cycle.try_wind_inner(cycles),
+            Self::Limited(cycle) => cycle.try_wind_inner(cycles),
+        }
+    }
+
+    fn try_unwind(&self, point: &CyclePoint<N, Self::Cycles>) -> Result<Self::Cycles, OverflowError> {
+        match self {
+            Self::Simple(cycle) => cycle.try_unwind(point),
+            Self::Limited(cycle) => cycle.try_unwind(point),
+        }
+    }
+}
+
+impl<R: PeriodRepresentation> CheckedCycle<R::Cycles> for Period<R> {
+    fn try_wind_inner(&self, cycles: &Self::Cycles) -> Result<CyclePoint<R::Cycles, Self::Cycles>, OverflowError> {
+        Ok(self.wind_inner(cycles))
+    }
+
+    fn try_unwind(&self, point: &CyclePoint<R::Cycles, Self::Cycles>) -> Result<Self::Cycles, OverflowError> {
+        checked_unwind(point.cycle.out(), self.size().out(), point.phase.out()).map(Integer::new)
+    }
+}
+
+impl<N: NumBase, C: CheckedCycle<N, Cycles = Natural<N>>> CheckedCycle<N> for Offset<C, Natural<N>> {
+    fn try_wind_inner(&self, cycles: &Self::Cycles) -> Result<CyclePoint<N, Self::Cycles>, OverflowError> {
+        let shifted = cycles
+            .out()
+            .checked_add(&self.offset.out())
+            .ok_or(OverflowError::Wind)?;
+        self.inner.try_wind_inner(&Natural::new_unchecked(shifted))
+    }
+
+    fn try_unwind(&self, point: &CyclePoint<N, Self::Cycles>) -> Result<Self::Cycles, OverflowError> {
+        // Points below the offset are not reached by any natural total
+        let total = self.inner.try_unwind(point)?;
+        total.checked_sub(self.offset).ok_or(OverflowError::Unwind)
+    }
+}
+
+impl<N: NumBase, L: NumBase, C: CheckedCycle<N, Cycles = Integer<L>>> CheckedCycle<N> for Offset<C, Integer<L>> {
+    fn try_wind_inner(&self, cycles: &Self::Cycles) -> Result<CyclePoint<N, Self::Cycles>, OverflowError> {
+        let shifted = cycles
+            .out()
+            .checked_add(&self.offset.out())
+            .ok_or(OverflowError::Wind)?;
+        self.inner.try_wind_inner(&Integer::new(shifted))
+    }
+
+    fn try_unwind(&self, point: &CyclePoint<N, Self::Cycles>) -> Result<Self::Cycles, OverflowError> {
+        let total = self.inner.try_unwind(point)?.out();
+        let shifted = total.checked_sub(&self.offset.out()).ok_or(OverflowError::Unwind)?;
+        Ok(Integer::new(shifted))
+    }
+}
+
+impl<R, C, const N: usize> CheckedMixed<N> for SimpleMixed<R, C, N>
+where
+    R: PeriodRepresentation,
+    C: Cycle<R::Cycles, Cycles = Natural<R::Cycles>>,
+{
+    fn try_wind_inner(&self, total: &Self::Cycles) -> Result<MixedPoint<Self::U, N, Self::Cycles>, OverflowError> {
+        Ok(self.wind_inner(total))
+    }
+
+    fn try_unwind(&self, point: &MixedPoint<Self::U, N, Self::Cycles>) -> Result<Self::Cycles, OverflowError> {
+        let MixedPoint { cycle, phase } = point;
+        let mut total = *cycle;
+        for k in (0..N).rev() {
+            let period = Period::<R>::new(self.factors[k].size());
+            total = period.try_unwind(&CyclePoint::new(total, R::embed(phase[k])))?;
+        }
+        Ok(total)
+    }
+}
+
+impl<const N: usize, L: NumBase, M: CheckedMixed<N, Cycles = Integer<L>>> CheckedMixed<N> for Offset<M, Integer<L>> {
+    fn try_wind_inner(&self, total: &Self::Cycles) -> Result<MixedPoint<Self::U, N, Self::Cycles>, OverflowError> {
+        let shifted = total.out().checked_add(&self.offset.out()).ok_or(OverflowError::Wind)?;
+        self.inner.try_wind_inner(&Integer::new(shifted))
+    }
+
+    fn try_unwind(&self, point: &MixedPoint<Self::U, N, Self::Cycles>) -> Result<Self::Cycles, OverflowError> {
+        let total = self.inner.try_unwind(point)?.out();
+        let shifted = total.checked_sub(&self.offset.out()).ok_or(OverflowError::Unwind)?;
+        Ok(Integer::new(shifted))
+    }
+}
+
+impl<R, C, const N: usize> CheckedFilter<N> for SimpleFilter<R, C, N>
+where
+    R: PeriodRepresentation,
+    C: CheckedCycle<R::Cycles, Cycles = Natural<R::Cycles>>,
+{
+    fn try_wind_inner(&self, total: &Self::Cycle) -> Result<FilterPoint<Self::U, N, Self::Cycle>, OverflowError> {
+        Ok(self.wind_inner(total))
+    }
+
+    fn try_unwind(&self, point: &FilterPoint<Self::U, N, Self::Cycle>) -> Result<Self::Cycle, OverflowError> {
+        let FilterPoint { point, remainder } = point;
+        let mut phase = R::embed(*remainder);
+        for k in (0..N).rev() {
+            phase = self.factors[k].try_unwind(&CyclePoint::new(R::embed(point.phase[N - k - 1]), phase))?;
+        }
+        self.period.try_unwind(&CyclePoint::new(point.cycle, phase))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use numburs::{int, integral::testing::*, nat, pos, ConstrainedRep, Integer};
+
+    use super::{CheckedCycle, CheckedFilter, CheckedMixed, OverflowError};
+    use crate::{
+        cycle::{
+            limited_cycle::LimitedCycle,
+            period::{IdentityPeriodRepresentation, Period},
+            simple_cycle::SimpleCycle,
+            types::CyclePoint,
+        },
+        filter::{simple::SimpleFilter, types::Filter},
+        mixed::{
+            simple::SimpleMixed,
+            types::{Mixed, MixedPoint},
+        },
+        offset::Offset,
+    };
+
+    type Rep = IdentityPeriodRepresentation<i64>;
+    type MixedType = SimpleMixed<Rep, SimpleCycle<i64>, 3>;
+
+    const MIXED: MixedType = MixedType::from_sizes([8, 2, 16]);
+
+    #[test]
+    fn cycles_at_extremes() {
+        let cycle = SimpleCycle::new(P7);
+        let top = cycle.try_wind(&nat!(i64::MAX)).unwrap().point;
+        assert_eq!(Ok(nat!(i64::MAX)), cycle.try_unwind(&top));
+        let past = CyclePoint::new(top.cycle, nat!(top.phase.out() + 1));
+        assert_eq!(Err(OverflowError::Unwind), cycle.try_unwind(&past));
+
+        let limited = LimitedCycle::new(P3, P2);
+        assert_eq!(
+            Err(OverflowError::Unwind),
+            limited.try_unwind(&CyclePoint::new(N2, nat!(i64::MAX)))
+        );
+
+        let period = Period::<Rep>::new(P7);
+        for total in [i64::MIN, i64::MIN + 1, i64::MAX - 1, i64::MAX] {
+            let point = period.try_wind(&int!(total)).unwrap().point;
+            assert_eq!(Ok(int!(total)), period.try_unwind(&point));
+        }
+        let below = CyclePoint::new(int!(i64::MIN / 7 - 2), N0);
+        assert_eq!(Err(OverflowError::Unwind), period.try_unwind(&below));
+    }
+
+    #[test]
+    fn mixed_at_extremes() {
+        for total in [i64::MIN, i64::MIN + 255, -1, 0, i64::MAX - 256, i64::MAX] {
+            let point = MIXED.try_wind(int!(total)).unwrap().point;
+            assert_eq!(MIXED.wind_inner(&int!(total)), point);
+            assert_eq!(Ok(int!(total)), MIXED.try_unwind(&point));
+        }
+        let top = MIXED.wind_inner(&int!(i64::MAX));
+        let past = MixedPoint::new(top.cycle + I1, [N0, N0, N0]);
+        assert_eq!(Err(OverflowError::Unwind), MIXED.try_unwind(&past));
+        assert_eq!(
+            Err(OverflowError::Unwind),
+            MIXED.try_unwind(&MixedPoint::new(int!(i64::MIN / 256 - 1), [N0, N0, N0]))
+        );
+    }
+
+    #[test]
+    fn offsets_narrow_the_range() {
+        let shifted = Offset::new(MIXED, int!(10));
+        assert_eq!(
+            Err(OverflowError::Wind),
+            shifted.try_wind(int!(i64::MAX - 9)).map(|p| p.point)
+        );
+        let point = shifted.try_wind_inner(&int!(i64::MAX - 10)).unwrap();
+        assert_eq!(Ok(int!(i64::MAX - 10)), shifted.try_unwind(&point));
+
+        let back = Offset::new(Period::<Rep>::new(P7), int!(-10));
+        assert_eq!(
+            Err(OverflowError::Wind),
+            back.try_wind(&int!(i64::MIN + 9)).map(|p| p.point)
+        );
+        let point = CyclePoint::new(int!(i64::MAX / 7), N0);
+        assert_eq!(Err(OverflowError::Unwind), back.try_unwind(&point));
+
+        let natural = Offset::new(SimpleCycle::new(P7), N3);
+        assert_eq!(
+            Err(OverflowError::Wind),
+            natural.try_wind(&nat!(i64::MAX)).map(|p| p.point)
+        );
+        assert_eq!(Err(OverflowError::Unwind), natural.try_unwind(&CyclePoint::new(N0, N2)));
+        assert_eq!(Ok(N0), natural.try_unwind(&CyclePoint::new(N0, N3)));
+    }
+
+    #[test]
+    fn filter_at_extremes() {
+        let filter = SimpleFilter::<Rep, _, 2>::new(
+            Period::new(pos!(50)),
+            [
+                LimitedCycle::new(pos!(10), pos!(3)),
+                LimitedCycle::new(pos!(3), pos!(2)),
+            ],
+        );
+        for total in [i64::MIN, i64::MAX] {
+            let point = filter.try_wind(int!(total)).unwrap().point;
+            assert_eq!(filter.wind_inner(&int!(total)), point);
+            assert_eq!(Ok(int!(total)), filter.try_unwind(&point));
+        }
+        let mut point = filter.wind_inner(&int!(i64::MAX));
+        point.remainder = nat!(point.remainder.out() + 10);
+        assert_eq!(Err(OverflowError::Unwind), filter.try_unwind(&point));
+        assert_eq!(
+            "the point unwinds to a total out of range",
+            OverflowError::Unwind.to_string()
+        );
+    }
+}
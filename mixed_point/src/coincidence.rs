@@ -0,0 +1,262 @@
+use numburs::{ConstrainedRep, HasOne, HasZero, Integer, Natural, NumBase, Positive};
+
+use crate::{
+    cycle::{
+        period::PeriodRepresentation,
+        types::{Cycle, CyclePoint},
+    },
+    mixed::{projection::gcd, simple::SimpleMixed, types::Mixed},
+    types::Phase,
+};
+
+// Totals congruent to `residue` modulo `modulus`
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct Constraint<U: NumBase> {
+    pub modulus: Positive<U>,
+    pub residue: Natural<U>,
+}
+
+impl<U: NumBase> Constraint<U> {
+    pub fn new(modulus: Positive<U>, residue: Integer<U>) -> Self {
+        let (_, residue) = modulus.euclid(residue);
+        Self { modulus, residue }
+    }
+
+    // Totals at which a periodic cycle is at `phase`
+    pub fn of_cycle<C: Cycle<U, Cycles = Integer<U>>>(cycle: &C, phase: Natural<U>) -> Self {
+        Self::new(cycle.size(), cycle.unwind(&CyclePoint::new(Integer::ZERO, phase)))
+    }
+
+    // Totals at which the lowest `levels` digits of a mixed system match `phase`
+    pub fn of_mixed<R, C, const N: usize>(mixed: &SimpleMixed<R, C, N>, phase: &Phase<U, N>, levels: usize) -> Self
+    where
+        R: PeriodRepresentation<Phase = U, Cycles = U>,
+        C: Cycle<U, Cycles = Natural<U>>,
+    {
+        let residue = (0..levels).fold(Natural::ZERO, |r, k| r + phase[k] * mixed.stride(k));
+        Self::new(mixed.stride(levels), residue.into())
+    }
+}
+
+// All totals satisfying a set of constraints, which repeat every `period`
+// (the least common multiple of the moduli) starting from `first`
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct Coincidence<U: NumBase> {
+    pub period: Positive<U>,
+    pub first: Natural<U>,
+}
+
+// The inverse of `a` modulo `n`, for coprime `a` and `n`, or None when the
+// intermediate sums overflow
+fn inverse<U: NumBase>(a: U, n: U) -> Option<U> {
+    let (mut r0, mut r1) = (n, a);
+    let (mut t0, mut t1) = (U::ZERO, U::ONE);
+    while r1 != U::ZERO {
+        let q = r0 / r1;
+        (r0, r1) = (r1, r0 - q * r1);
+        let t = t0.checked_add(&n)? - q.checked_mul(&t1)? % n;
+        (t0, t1) = (t1, t % n);
+    }
+    Some(t0)
+}
+
+impl<U: NumBase> Coincidence<U> {
+    // None when the constraints never coincide, or when the combined period
+    // or the arithmetic to find it does not fit in U
+    pub fn solve(constraints: &[Constraint<U>]) -> Option<Self> {
+        let always = Self {
+            period: Positive::ONE,
+            first: Natural::ZERO,
+        };
+        constraints
+            .iter()
+            .try_fold(always, |found, constraint| found.merge(constraint))
+    }
+
+    // Finds the k for which first + k * period meets the constraint, by the
+    // Chinese remainder theorem when coprime and by search otherwise
+    fn merge(self, constraint: &Constraint<U>) -> Option<Self> {
+        let (m, r) = (self.period.out(), self.first.out());
+        let (n, s) = (constraint.modulus.out(), constraint.residue.out());
+        let g = gcd(m, n);
+        let k = if g == U::ONE {
+            let difference = (s.checked_add(&n)? - r % n) % n;
+            difference.checked_mul(&inverse(m % n, n)?)? % n
+        } else {
+            let mut k = U::ZERO;
+            while r.checked_add(&k.checked_mul(&m)?)? % n != s {
+                k = k + U::ONE;
+                if k == n / g {
+                    return None;
+                }
+            }
+            k
+        };
+        // Neither can go negative, so only overflow can fail
+        let period = (m / g).checked_mul(&n)?;
+        let first = r.checked_add(&k.checked_mul(&m)?)?;
+        Some(Self {
+            period: Positive::new_unchecked(period),
+            first: Natural::new_unchecked(first),
+        })
+    }
+
+    pub fn is_match(&self, total: Integer<U>) -> bool {
+        let (_, phase) = self.period.euclid(total);
+        phase == self.first
+    }
+
+    // The first coinciding total at or after `total`, or None when it is past
+    // the largest total
+    pub fn next(&self, total: Integer<U>) -> Option<Integer<U>> {
+        let (_, phase) = self.period.euclid(total);
+        let (period, phase, first) = (self.period.out(), phase.out(), self.first.out());
+        // Phase and first are both below the period, so only the last step can overflow
+        let step = if phase <= first {
+            first - phase
+        } else {
+            period - (phase - first)
+        };
+        total.out().checked_add(&step).map(Integer::new)
+    }
+
+    // The last coinciding total at or before `total`, or None when it is
+    // before the smallest total
+    pub fn previous(&self, total: Integer<U>) -> Option<Integer<U>> {
+        let (_, phase) = self.period.euclid(total);
+        let (period, phase, first) = (self.period.out(), phase.out(), self.first.out());
+        let step = if phase >= first {
+            phase - first
+        } else {
+            period - (first - phase)
+        };
+        total.out().checked_sub(&step).map(Integer::new)
+    }
+
+    // Every coinciding total from `total` onwards, up to the largest total
+    pub fn iter(&self, total: Integer<U>) -> impl Iterator<Item = Integer<U>> {
+        let period = self.period.out();
+        core::iter::successors(self.next(total), move |found| {
+            found.out().checked_add(&period).map(Integer::new)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use numburs::{int, integral::testing::*, nat, pos, Integer};
+
+    use super::{Coincidence, Constraint};
+    use crate::{
+        cycle::period::{IdentityPeriodRepresentation, Period},
+        mixed::simple::SimpleMixed,
+        offset::Offset,
+        SimpleCycle,
+    };
+
+    type Rep = IdentityPeriodRepresentation<i64>;
+    type MixedType = SimpleMixed<Rep, SimpleCycle<i64>, 3>;
+
+    fn constraint(modulus: i64, residue: i64) -> Constraint<i64> {
+        Constraint::new(pos!(modulus), int!(residue))
+    }
+
+    #[test]
+    fn coprime() {
+        let found = Coincidence::solve(&[constraint(3, 2), constraint(5, 3), constraint(7, 2)]).unwrap();
+        assert_eq!(
+            Coincidence {
+                period: pos!(105),
+                first: nat!(23)
+            },
+            found
+        );
+        assert_eq!(Some(int!(128)), found.next(int!(24)));
+        assert_eq!(Some(int!(23)), found.next(int!(23)));
+        assert_eq!(Some(int!(-82)), found.next(int!(-100)));
+        assert_eq!(Some(int!(23)), found.previous(int!(127)));
+        assert_eq!(Some(int!(-82)), found.previous(int!(22)));
+        assert_eq!(
+            vec![int!(23), int!(128), int!(233)],
+            found.iter(I0).take(3).collect::<Vec<_>>()
+        );
+        for k in -300i64..300 {
+            let expected = k.rem_euclid(3) == 2 && k.rem_euclid(5) == 3 && k.rem_euclid(7) == 2;
+            assert_eq!(expected, found.is_match(int!(k)));
+        }
+    }
+
+    #[test]
+    fn not_coprime() {
+        let found = Coincidence::solve(&[constraint(4, 1), constraint(6, 3)]).unwrap();
+        assert_eq!(
+            Coincidence {
+                period: pos!(12),
+                first: nat!(9)
+            },
+            found
+        );
+        assert_eq!(None, Coincidence::solve(&[constraint(4, 1), constraint(6, 2)]));
+    }
+
+    #[test]
+    fn overflow() {
+        let byte = |modulus: u8, residue: u8| Constraint::new(pos!(modulus), Integer::new(residue));
+        assert_eq!(
+            Some(Coincidence {
+                period: pos!(120u8),
+                first: nat!(11u8)
+            }),
+            Coincidence::solve(&[byte(8, 3), byte(15, 11)])
+        );
+        assert_eq!(None, Coincidence::solve(&[byte(16, 3), byte(17, 5)]));
+        assert_eq!(None, Coincidence::solve(&[byte(12, 3), byte(50, 5)]));
+    }
+
+    #[test]
+    fn extremes() {
+        let found = Coincidence::solve(&[constraint(3, 2), constraint(5, 3), constraint(7, 2)]).unwrap();
+        let (last, first) = (int!(i64::MAX - 89), int!(i64::MIN + 31));
+        assert_eq!(None, found.next(int!(i64::MAX)));
+        assert_eq!(Some(last), found.next(int!(i64::MAX - 100)));
+        assert_eq!(Some(last), found.previous(int!(i64::MAX)));
+        assert_eq!(None, found.previous(int!(i64::MIN)));
+        assert_eq!(Some(first), found.next(int!(i64::MIN)));
+        assert!(found.is_match(last) && found.is_match(first));
+        assert!(!found.is_match(int!(i64::MIN)) && !found.is_match(int!(i64::MAX)));
+        assert_eq!(
+            vec![last - int!(105), last],
+            found.iter(int!(i64::MAX - 200)).collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn empty() {
+        let found = Coincidence::solve(&[]).unwrap();
+        assert_eq!(Some(int!(-5)), found.next(int!(-5)));
+    }
+
+    #[test]
+    fn cycles_and_mixed() {
+        let week = Offset::new(Period::<Rep>::new(P7), I3);
+        assert_eq!(constraint(7, 4), Constraint::of_cycle(&week, N0));
+
+        let mixed = MixedType::from_sizes([2, 3, 4]);
+        assert_eq!(constraint(6, 5), Constraint::of_mixed(&mixed, &[N1, N2, N0], 2));
+        assert_eq!(constraint(24, 5), Constraint::of_mixed(&mixed, &[N1, N2, N0], 3));
+
+        // The next time the week starts on a total with digits [0, 0, *]
+        let found = Coincidence::solve(&[
+            Constraint::of_cycle(&week, N0),
+            Constraint::of_mixed(&mixed, &[N0, N0, N0], 2),
+        ])
+        .unwrap();
+        assert_eq!(
+            Coincidence {
+                period: pos!(42),
+                first: nat!(18)
+            },
+            found
+        );
+    }
+}
@@ -0,0 +1,101 @@
+use numburs::{Natural, NumBase, Positive};
+
+use super::{
+    limited_cycle::LimitedCycle,
+    simple_cycle::SimpleCycle,
+    types::{Cycle, CyclePoint},
+};
+
+// Any of the cycle types, so that a system can vary them level by level
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum CycleKind<N: NumBase> {
+    Simple(SimpleCycle<N>),
+    Limited(LimitedCycle<N>),
+}
+
+impl<N: NumBase> CycleKind<N> {
+    pub const fn simple(size: Positive<N>) -> Self {
+        Self::Simple(SimpleCycle::new(size))
+    }
+
+    pub const fn limited(size: Positive<N>, limit: Positive<N>) -> Self {
+        Self::Limited(LimitedCycle::new(size, limit))
+    }
+}
+
+impl<N: NumBase> From<SimpleCycle<N>> for CycleKind<N> {
+    fn from(cycle: SimpleCycle<N>) -> Self {
+        Self::Simple(cycle)
+    }
+}
+
+impl<N: NumBase> From<LimitedCycle<N>> for CycleKind<N> {
+    fn from(cycle: LimitedCycle<N>) -> Self {
+        Self::Limited(cycle)
+    }
+}
+
+impl<N: NumBase> Cycle<N> for CycleKind<N> {
+    type Cycles = Natural<N>;
+
+    fn size(&self) -> Positive<N> {
+        match self {
+            Self::Simple(cycle) => cycle.size(),
+            Self::Limited(cycle) => cycle.size(),
+        }
+    }
+
+    fn is_norm(&self, point: &CyclePoint<N, Self::Cycles>) -> bool {
+        match self {
+            Self::Simple(cycle) => cycle.is_norm(point),
+            Self::Limited(cycle) => cycle.is_norm(point),
+        }
+    }
+
+    fn wind_inner(&self, cycles: &Self::Cycles) -> CyclePoint<N, Self::Cycles> {
+        match self {
+            Self::Simple(cycle) => cycle.wind_inner(cycles),
+            Self::Limited(cycle) => cycle.wind_inner(cycles),
+        }
+    }
+
+    fn unwind(&self, point: &CyclePoint<N, Self::Cycles>) -> Self::Cycles {
+        match self {
+            Self::Simple(cycle) => cycle.unwind(point),
+            Self::Limited(cycle) => cycle.unwind(point),
+        }
+    }
+
+    fn cycle_limit(&self) -> Option<Positive<N>> {
+        match self {
+            Self::Simple(cycle) => cycle.cycle_limit(),
+            Self::Limited(cycle) => cycle.cycle_limit(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use numburs::{nat, pos};
+
+    use super::CycleKind;
+    use crate::cycle::{limited_cycle::LimitedCycle, simple_cycle::SimpleCycle, types::Cycle};
+
+    #[test]
+    fn cycle_kind_delegates() {
+        let simple = SimpleCycle::new(pos!(4));
+        let limited = LimitedCycle::new(pos!(4), pos!(2));
+        for k in 0..20 {
+            assert_eq!(
+                simple.wind(&nat!(k)).point,
+                CycleKind::from(simple).wind(&nat!(k)).point
+            );
+            assert_eq!(
+                limited.wind(&nat!(k)).point,
+                CycleKind::from(limited).wind(&nat!(k)).point
+            );
+        }
+        assert_eq!(CycleKind::Simple(simple), CycleKind::simple(pos!(4)));
+        assert_eq!(CycleKind::Limited(limited), CycleKind::limited(pos!(4), pos!(2)));
+    }
+}
@@ -1,14 +1,23 @@
 use core::{cmp::min, ops::Mul};
 
-use derive_more::Constructor;
-use numburs::{ConstrainedRep, LowerBoundedRep, Natural, NumBase, Positive};
+use numburs::{ConstrainedSub, Natural, NumBase, Positive};
 
 use super::types::{Cycle, CyclePoint};
 
-#[derive(Debug, PartialEq, Eq, Constructor, Clone)]
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
 pub struct LimitedCycle<N: NumBase> {
-    size: Positive<N>,
-    limit: Positive<N>,
+    pub(crate) size: Positive<N>,
+    pub(crate) limit: Positive<N>,
+}
+
+impl<N: NumBase> LimitedCycle<N> {
+    pub const fn new(size: Positive<N>, limit: Positive<N>) -> Self {
+        Self { size, limit }
+    }
+
+    pub fn limit(&self) -> Positive<N> {
+        self.limit
+    }
 }
 
 impl<N: NumBase> Cycle<N> for LimitedCycle<N> {
@@ -19,18 +28,23 @@ impl<N: NumBase> Cycle<N> for LimitedCycle<N> {
     }
 
     fn is_norm(&self, point: &CyclePoint<N, Self::Cycles>) -> bool {
-        point.phase < self.size.into() || point.cycle == self.limit.into()
+        point.cycle <= self.limit.into() && (point.phase < self.size.into() || point.cycle == self.limit.into())
     }
 
     fn wind_inner(&self, cycles: &Natural<N>) -> CyclePoint<N, Self::Cycles> {
         let q = min(*cycles / self.size, self.limit.into());
-        CyclePoint::new(q, Natural::at_least(cycles.out() - q.out() * self.size.out()))
+        let phase = cycles.checked_sub(q * self.size).expect("q cycles fit within cycles");
+        CyclePoint::new(q, phase)
     }
 
     fn unwind(&self, point: &CyclePoint<N, Self::Cycles>) -> Self::Cycles {
         let CyclePoint { cycle, phase } = point;
         (*cycle) * self.size() + *phase
     }
+
+    fn cycle_limit(&self) -> Option<Positive<N>> {
+        Some(self.limit)
+    }
 }
 
 impl<N: NumBase> Mul for LimitedCycle<N> {
@@ -43,7 +57,7 @@ impl<N: NumBase> Mul for LimitedCycle<N> {
 
 #[cfg(test)]
 mod tests {
-    use numburs::{nat, pos, LowerBoundedRep, Natural, Positive};
+    use numburs::{nat, pos};
 
     use super::{super::types::Cycle, LimitedCycle};
 
@@ -65,4 +79,13 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    fn test_limited_cycle_norm() {
+        let cycle = LimitedCycle::new(pos!(3), pos!(2));
+        assert!(cycle.point(nat!(2), nat!(7)).is_some());
+        assert!(cycle.point(nat!(1), nat!(2)).is_some());
+        assert!(cycle.point(nat!(1), nat!(3)).is_none());
+        assert!(cycle.point(nat!(3), nat!(0)).is_none());
+    }
 }
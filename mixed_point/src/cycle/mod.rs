@@ -1,8 +1,10 @@
+pub mod cycle_kind;
 pub mod limited_cycle;
 pub mod period;
 pub mod simple_cycle;
 pub mod types;
 
+pub use cycle_kind::*;
 pub use limited_cycle::*;
 pub use period::*;
 pub use simple_cycle::*;
@@ -1,7 +1,8 @@
 use core::fmt::Debug;
 use std::marker::PhantomData;
 
-use numburs::{FloatBase, Integer, Natural, NumBase, Positive, Real, WithFraction};
+use num_traits::AsPrimitive;
+use numburs::{ConstrainedRep, FloatBase, Integer, Natural, NumBase, Positive, Real, WithFraction};
 
 use super::types::{BoundCyclePoint, Cycle, CyclePoint};
 
@@ -14,14 +15,16 @@ pub trait PeriodRepresentation {
     fn project(l: Natural<Self::Cycles>) -> Natural<Self::Phase>;
 }
 
+// Sized in the cycle type, so that only the digits below it have to fit the
+// phase type
 #[derive(Debug, PartialEq, Eq, Clone)]
 pub struct Period<R: PeriodRepresentation> {
-    size: Positive<R::Phase>,
+    pub(crate) size: Positive<R::Cycles>,
     representation: PhantomData<R>,
 }
 
 impl<R: PeriodRepresentation> Period<R> {
-    pub fn new(size: Positive<R::Phase>) -> Self {
+    pub const fn new(size: Positive<R::Cycles>) -> Self {
         Self {
             size,
             representation: PhantomData,
@@ -29,33 +32,30 @@ impl<R: PeriodRepresentation> Period<R> {
     }
 }
 
-impl<R: PeriodRepresentation> Cycle<R::Phase> for Period<R> {
+impl<R: PeriodRepresentation> Cycle<R::Cycles> for Period<R> {
     type Cycles = Integer<R::Cycles>;
 
-    fn size(&self) -> Positive<R::Phase> {
+    fn size(&self) -> Positive<R::Cycles> {
         self.size
     }
 
-    fn is_norm(&self, point: &CyclePoint<R::Phase, Self::Cycles>) -> bool {
+    fn is_norm(&self, point: &CyclePoint<R::Cycles, Self::Cycles>) -> bool {
         point.phase < self.size.into()
     }
 
-    fn wind_inner(&self, cycles: &Self::Cycles) -> CyclePoint<R::Phase, Self::Cycles> {
-        let size_l = R::embed_positive(self.size);
-        let (cycle, phase) = size_l.euclid(*cycles);
-        CyclePoint::new(cycle, R::project(phase))
+    fn wind_inner(&self, cycles: &Self::Cycles) -> CyclePoint<R::Cycles, Self::Cycles> {
+        let (cycle, phase) = self.size.euclid(*cycles);
+        CyclePoint::new(cycle, phase)
     }
 
-    fn unwind(&self, point: &CyclePoint<R::Phase, Self::Cycles>) -> Self::Cycles {
+    fn unwind(&self, point: &CyclePoint<R::Cycles, Self::Cycles>) -> Self::Cycles {
         let CyclePoint { cycle, phase } = point;
-        let size: Self::Cycles = R::embed_positive(self.size()).into();
-        let c_phase: Self::Cycles = R::embed(*phase).into();
-        (*cycle) * size + c_phase
+        (*cycle) * Integer::from(self.size) + Integer::from(*phase)
     }
 }
 
 impl<R: PeriodRepresentation> Period<R> {
-    pub fn wind_with_float<F: FloatBase>(&self, r: Real<F>) -> WithFraction<BoundCyclePoint<R::Phase, Self>, F> {
+    pub fn wind_with_float<F: FloatBase>(&self, r: Real<F>) -> WithFraction<BoundCyclePoint<R::Cycles, Self>, F> {
         let WithFraction { whole, fraction } = r.fractionalize();
         WithFraction::new(self.wind(&whole), fraction)
     }
@@ -83,13 +83,46 @@ impl<U: NumBase> PeriodRepresentation for IdentityPeriodRepresentation<U> {
     }
 }
 
+// Digits in a narrow type and everything else in a wide one, such as u8
+// digits of a system counting cycles in i64. Only the digits, each below the
+// size of its factor, have to fit the narrow type.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct WideningPeriodRepresentation<P: NumBase, C: NumBase> {
+    phantom: PhantomData<(P, C)>,
+}
+
+impl<P, C> PeriodRepresentation for WideningPeriodRepresentation<P, C>
+where
+    P: NumBase + 'static,
+    C: NumBase + From<P> + AsPrimitive<P>,
+{
+    type Cycles = C;
+    type Phase = P;
+
+    fn embed_positive(u: Positive<Self::Phase>) -> Positive<Self::Cycles> {
+        Positive::new_unchecked(<C as From<P>>::from(u.out()))
+    }
+
+    fn embed(u: Natural<Self::Phase>) -> Natural<Self::Cycles> {
+        Natural::new_unchecked(<C as From<P>>::from(u.out()))
+    }
+
+    // Checked in release builds too, since a factor too large for the phase
+    // type would otherwise give wrong digits without any error
+    fn project(l: Natural<Self::Cycles>) -> Natural<Self::Phase> {
+        assert!(
+            l.out() <= <C as From<P>>::from(P::max_value()),
+            "digit does not fit in the phase type"
+        );
+        Natural::new_unchecked(l.out().as_())
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use numburs::{
-        int, integral::testing::*, nat, pos, Fractional, Integer, LowerBoundedRep, Natural, Positive, WithFraction,
-    };
+    use numburs::{int, integral::testing::*, nat, pos, Fractional, Integer, WithFraction};
 
-    use super::{Cycle, IdentityPeriodRepresentation, Period};
+    use super::{Cycle, IdentityPeriodRepresentation, Period, WideningPeriodRepresentation};
 
     #[test]
     fn test_period_construction() {
@@ -119,7 +152,7 @@ mod tests {
         // TODO: Tests with fractionals, negative, etc...
         let period: Period<IdentityPeriodRepresentation<i64>> = Period::new(P1);
         assert_eq!(
-            WithFraction::new(period.point(1.into(), N0).unwrap(), Fractional::from(0.)),
+            WithFraction::new(period.point(1.into(), N0).unwrap(), Fractional::try_from(0.).unwrap()),
             period.wind_with_float((1.0).into())
         );
     }
@@ -140,4 +173,14 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    fn widening_period() {
+        let period: Period<WideningPeriodRepresentation<u16, i128>> = Period::new(pos!(360));
+        let point = period.wind(&int!(-1)).point;
+        assert_eq!((int!(-1), nat!(359)), (point.cycle, point.phase));
+        let far = i128::from(i64::MAX) * 1000 + 7;
+        assert_eq!(nat!(far % 360), period.wind(&int!(far)).point.phase);
+        assert_eq!(int!(far), period.unwind(&period.wind(&int!(far)).point));
+    }
 }
@@ -1,11 +1,16 @@
-use derive_more::Constructor;
 use numburs::{Natural, NumBase, Positive};
 
 use super::types::{Cycle, CyclePoint};
 
-#[derive(Debug, PartialEq, Eq, Constructor, Clone)]
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
 pub struct SimpleCycle<N: NumBase> {
-    size: Positive<N>,
+    pub(crate) size: Positive<N>,
+}
+
+impl<N: NumBase> SimpleCycle<N> {
+    pub const fn new(size: Positive<N>) -> Self {
+        Self { size }
+    }
 }
 
 impl<N: NumBase> Cycle<N> for SimpleCycle<N> {
@@ -31,7 +36,7 @@ impl<N: NumBase> Cycle<N> for SimpleCycle<N> {
 
 #[cfg(test)]
 mod tests {
-    use numburs::{integral::testing::*, nat, pos, LowerBoundedRep, Natural, Positive};
+    use numburs::{integral::testing::*, nat, pos};
 
     use super::SimpleCycle;
     use crate::cycle::types::{Cycle, CyclePoint};
@@ -1,7 +1,9 @@
 use derive_more::Constructor;
 use numburs::{Natural, NumBase, Positive};
 
-#[derive(Debug, PartialEq, Eq, Constructor)]
+use crate::trace::TraceStep;
+
+#[derive(Debug, PartialEq, Eq, Constructor, Clone, Copy)]
 pub struct CyclePoint<N: NumBase, Cycles> {
     pub cycle: Cycles,
     pub phase: Natural<N>,
@@ -21,6 +23,11 @@ pub trait Cycle<N: NumBase>: Sized {
     fn wind_inner(&self, cycles: &Self::Cycles) -> CyclePoint<N, Self::Cycles>;
     fn unwind(&self, point: &CyclePoint<N, Self::Cycles>) -> Self::Cycles;
 
+    // The cycle count past which winding stops, leaving the excess in the phase
+    fn cycle_limit(&self) -> Option<Positive<N>> {
+        None
+    }
+
     fn bind(&self, point: CyclePoint<N, Self::Cycles>) -> Option<BoundCyclePoint<N, Self>> {
         Some(point)
             .filter(|p| self.is_norm(p))
@@ -34,4 +41,18 @@ pub trait Cycle<N: NumBase>: Sized {
     fn wind(&self, cycles: &Self::Cycles) -> BoundCyclePoint<N, Self> {
         BoundCyclePoint::new(self, self.wind_inner(cycles))
     }
+
+    fn wind_trace(&self, cycles: &Self::Cycles) -> TraceStep<N, Self::Cycles>
+    where
+        Self::Cycles: Clone,
+    {
+        let point = self.wind_inner(cycles);
+        let clamped = point.phase >= self.size().into();
+        TraceStep::new(self.size(), cycles.clone(), point, clamped)
+    }
+
+    fn unwind_trace(&self, point: CyclePoint<N, Self::Cycles>) -> TraceStep<N, Self::Cycles> {
+        let clamped = point.phase >= self.size().into();
+        TraceStep::new(self.size(), self.unwind(&point), point, clamped)
+    }
 }
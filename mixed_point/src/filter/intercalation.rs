@@ -0,0 +1,197 @@
+use numburs::{ConstrainedRep, HasOne, HasZero, Integer, Natural, NumBase, Positive};
+
+use super::simple::SimpleFilter;
+use crate::{
+    cycle::{
+        cycle_kind::CycleKind,
+        period::{Period, PeriodRepresentation},
+        simple_cycle::SimpleCycle,
+    },
+    mixed::simple::SimpleMixed,
+};
+
+// Adds `adjust` to the last unit of every block of `every` units.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct IntercalationRule<N: NumBase> {
+    pub every: Positive<N>,
+    pub adjust: Integer<N>,
+}
+
+impl<N: NumBase> IntercalationRule<N> {
+    pub const fn new(every: Positive<N>, adjust: Integer<N>) -> Self {
+        Self { every, adjust }
+    }
+}
+
+// Units of `base` length grouped by `levels` (lowest first), with each rule
+// lengthening or shortening the last unit of its blocks. For example, the
+// Gregorian leap rule is a base of 365 with levels [4, 25, 4] and the rules
+// +1 every 4, -1 every 100 and +1 every 400.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Intercalation<N: NumBase, const L: usize, const K: usize> {
+    pub base: Positive<N>,
+    pub levels: [Positive<N>; L],
+    pub rules: [IntercalationRule<N>; K],
+}
+
+impl<N: NumBase, const L: usize, const K: usize> Intercalation<N, L, K> {
+    pub const fn new(base: Positive<N>, levels: [Positive<N>; L], rules: [IntercalationRule<N>; K]) -> Self {
+        Self { base, levels, rules }
+    }
+
+    fn applies(rule: &IntercalationRule<N>, span: Positive<N>) -> bool {
+        Natural::from(span) % rule.every == Natural::ZERO
+    }
+
+    fn is_aligned(&self, rule: &IntercalationRule<N>) -> bool {
+        let mut span = Positive::ONE;
+        for level in self.levels {
+            span = span * level;
+            if span == rule.every {
+                return true;
+            }
+        }
+        false
+    }
+
+    fn block_length(&self, span: Positive<N>) -> Option<Positive<N>> {
+        let mut length = Integer::from(span * self.base);
+        for rule in self.rules.iter().filter(|rule| Self::applies(rule, span)) {
+            length = length + rule.adjust * (Natural::from(span) / rule.every);
+        }
+        Positive::rep(length.out())
+    }
+
+    fn last_unit_length(&self, span: Positive<N>) -> Option<Positive<N>> {
+        let mut length = Integer::from(self.base);
+        for rule in self.rules.iter().filter(|rule| Self::applies(rule, span)) {
+            length = length + rule.adjust;
+        }
+        Positive::rep(length.out())
+    }
+
+    // The longest a block of `span` units gets, when it is also the last block
+    // of some of the larger blocks containing it
+    fn longest_block(&self, span: Positive<N>) -> Option<Positive<N>> {
+        let mut length = Integer::from(self.block_length(span)?);
+        let mut longest = length;
+        let mut outer = Positive::ONE;
+        for level in self.levels {
+            outer = outer * level;
+            if outer > span {
+                for rule in self.rules.iter().filter(|rule| rule.every == outer) {
+                    length = length + rule.adjust;
+                }
+                longest = longest.max(length);
+            }
+        }
+        Positive::rep(longest.out())
+    }
+
+    pub fn span(&self) -> Positive<N> {
+        self.levels.iter().fold(Positive::ONE, |span, level| span * *level)
+    }
+
+    pub fn mixed<R: PeriodRepresentation<Cycles = N>>(&self) -> SimpleMixed<R, SimpleCycle<N>, L> {
+        SimpleMixed::from_simple_factors(self.levels)
+    }
+
+    // Levels whose last unit never overruns are simple, the rest are limited
+    pub fn filter<R: PeriodRepresentation<Cycles = N>>(&self) -> Option<SimpleFilter<R, CycleKind<N>, L>> {
+        if !self.rules.iter().all(|rule| self.is_aligned(rule)) {
+            return None;
+        }
+
+        let mut span = Positive::ONE;
+        let mut factors = [CycleKind::simple(Positive::ONE); L];
+        for (k, level) in self.levels.iter().enumerate() {
+            self.last_unit_length(span)?;
+            let size = self.block_length(span)?;
+            let limit = Positive::rep(level.out() - N::ONE)?;
+            span = span * *level;
+            factors[L - k - 1] = if self.longest_block(span)? <= size * *level {
+                CycleKind::simple(size)
+            } else {
+                CycleKind::limited(size, limit)
+            };
+        }
+        self.last_unit_length(span)?;
+
+        Some(SimpleFilter::new(Period::new(self.block_length(span)?), factors))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use numburs::{int, pos, ConstrainedRep, Integer};
+
+    use super::{Intercalation, IntercalationRule};
+    use crate::{
+        cycle::{cycle_kind::CycleKind, period::Period},
+        filter::{simple::SimpleFilter, types::Filter},
+        IdentityPeriodRepresentation,
+    };
+
+    type Rep = IdentityPeriodRepresentation<i64>;
+
+    fn gregorian() -> Intercalation<i64, 3, 3> {
+        Intercalation::new(
+            pos!(365),
+            [pos!(4), pos!(25), pos!(4)],
+            [
+                IntercalationRule::new(pos!(4), int!(1)),
+                IntercalationRule::new(pos!(100), int!(-1)),
+                IntercalationRule::new(pos!(400), int!(1)),
+            ],
+        )
+    }
+
+    #[test]
+    fn gregorian_filter() {
+        let expected: SimpleFilter<Rep, CycleKind<i64>, 3> = SimpleFilter::new(
+            Period::new(pos!(146097)),
+            [
+                CycleKind::limited(pos!(36524), pos!(3)),
+                CycleKind::simple(pos!(1461)),
+                CycleKind::limited(pos!(365), pos!(3)),
+            ],
+        );
+        assert_eq!(Some(expected), gregorian().filter());
+        assert_eq!(pos!(400), gregorian().span());
+    }
+
+    #[test]
+    fn gregorian_leap_days() {
+        let filter: SimpleFilter<Rep, CycleKind<i64>, 3> = gregorian().filter().unwrap();
+        let year_length = |years: [i64; 3]| {
+            let start = |k: i64| filter.wind(int!(k)).point.point.phase.map(|p| p.out());
+            (0..146097).filter(|k| start(*k) == years).count()
+        };
+        assert_eq!(365, year_length([0, 0, 0]));
+        assert_eq!(366, year_length([3, 0, 0]));
+        assert_eq!(365, year_length([3, 24, 0]));
+        assert_eq!(366, year_length([3, 24, 3]));
+    }
+
+    #[test]
+    fn misaligned_rule() {
+        let intercalation = Intercalation::new(
+            pos!(365),
+            [pos!(4), pos!(25)],
+            [IntercalationRule::new(pos!(3), int!(1))],
+        );
+        assert_eq!(None, intercalation.filter::<Rep>());
+    }
+
+    #[test]
+    fn empty_unit() {
+        let intercalation = Intercalation::new(pos!(2), [pos!(4)], [IntercalationRule::new(pos!(4), int!(-2))]);
+        assert_eq!(None, intercalation.filter::<Rep>());
+    }
+
+    #[test]
+    fn single_level() {
+        let intercalation = Intercalation::new(pos!(3), [pos!(1)], []);
+        assert_eq!(None, intercalation.filter::<Rep>());
+    }
+}
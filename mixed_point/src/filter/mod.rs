@@ -1,5 +1,9 @@
+pub mod intercalation;
+pub mod nested;
 pub mod simple;
 pub mod types;
 
+pub use intercalation::*;
+pub use nested::*;
 pub use simple::*;
 pub use types::*;
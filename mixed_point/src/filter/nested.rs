@@ -0,0 +1,192 @@
+use derive_more::Constructor;
+use numburs::{ConstrainedRep, ConstraintError, Integer, Natural, NumBase};
+
+use super::{
+    simple::SimpleFilter,
+    types::{Filter, FilterPoint},
+};
+use crate::{
+    cycle::{period::PeriodRepresentation, types::Cycle},
+    mixed::{
+        simple::SimpleMixed,
+        types::{Mixed, MixedPoint},
+    },
+};
+
+// Something the remainder of a filter can be wound into
+pub trait Subdivide<U: NumBase> {
+    type Point;
+
+    fn subdivide(&self, remainder: Natural<U>) -> Self::Point;
+    // Fails for points before the start of the remainder, such as those with
+    // a negative cycle
+    fn recombine(&self, point: &Self::Point) -> Result<Natural<U>, ConstraintError>;
+}
+
+#[derive(Debug, PartialEq, Eq, Constructor, Clone)]
+pub struct NestedPoint<U: NumBase, const N: usize, Cycle, Inner> {
+    pub outer: MixedPoint<U, N, Cycle>,
+    pub inner: Inner,
+}
+
+// A filter whose remainder is wound further by `inner`, which can be another
+// filter, a nested filter or a mixed system
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct NestedFilter<O, I> {
+    pub outer: O,
+    pub inner: I,
+}
+
+impl<O, I> NestedFilter<O, I> {
+    pub const fn new(outer: O, inner: I) -> Self {
+        Self { outer, inner }
+    }
+
+    pub fn wind<const N: usize>(&self, total: O::Cycle) -> NestedPoint<O::U, N, O::Cycle, I::Point>
+    where
+        O: Filter<N>,
+        I: Subdivide<O::U>,
+    {
+        let FilterPoint { point, remainder } = self.outer.wind_inner(&total);
+        NestedPoint::new(point, self.inner.subdivide(remainder))
+    }
+
+    pub fn unwind<const N: usize>(
+        &self,
+        point: &NestedPoint<O::U, N, O::Cycle, I::Point>,
+    ) -> Result<O::Cycle, ConstraintError>
+    where
+        O: Filter<N>,
+        O::Cycle: Clone,
+        I: Subdivide<O::U>,
+    {
+        let remainder = self.inner.recombine(&point.inner)?;
+        Ok(self.outer.unwind(&FilterPoint::new(point.outer.clone(), remainder)))
+    }
+}
+
+impl<R, C, const N: usize> Subdivide<R::Phase> for SimpleFilter<R, C, N>
+where
+    R: PeriodRepresentation,
+    C: Cycle<R::Cycles, Cycles = Natural<R::Cycles>>,
+{
+    type Point = FilterPoint<R::Phase, N, Integer<R::Cycles>>;
+
+    fn subdivide(&self, remainder: Natural<R::Phase>) -> Self::Point {
+        self.wind_inner(&R::embed(remainder).into())
+    }
+
+    fn recombine(&self, point: &Self::Point) -> Result<Natural<R::Phase>, ConstraintError> {
+        let total = Natural::rep(self.unwind(point).out()).ok_or(ConstraintError::Negative)?;
+        Ok(R::project(total))
+    }
+}
+
+impl<R, C, const N: usize> Subdivide<R::Phase> for SimpleMixed<R, C, N>
+where
+    R: PeriodRepresentation,
+    C: Cycle<R::Cycles, Cycles = Natural<R::Cycles>>,
+{
+    type Point = MixedPoint<R::Phase, N, Integer<R::Cycles>>;
+
+    fn subdivide(&self, remainder: Natural<R::Phase>) -> Self::Point {
+        self.wind_inner(&R::embed(remainder).into())
+    }
+
+    fn recombine(&self, point: &Self::Point) -> Result<Natural<R::Phase>, ConstraintError> {
+        let total = Natural::rep(self.unwind(point).out()).ok_or(ConstraintError::Negative)?;
+        Ok(R::project(total))
+    }
+}
+
+impl<U, O, I, const N: usize> Subdivide<U> for NestedFilter<O, I>
+where
+    U: NumBase,
+    O: Filter<N> + Subdivide<U, Point = FilterPoint<O::U, N, O::Cycle>>,
+    O::Cycle: Clone,
+    I: Subdivide<O::U>,
+{
+    type Point = NestedPoint<O::U, N, O::Cycle, I::Point>;
+
+    fn subdivide(&self, remainder: Natural<U>) -> Self::Point {
+        let FilterPoint { point, remainder } = self.outer.subdivide(remainder);
+        NestedPoint::new(point, self.inner.subdivide(remainder))
+    }
+
+    fn recombine(&self, point: &Self::Point) -> Result<Natural<U>, ConstraintError> {
+        let remainder = self.inner.recombine(&point.inner)?;
+        self.outer.recombine(&FilterPoint::new(point.outer.clone(), remainder))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use numburs::{int, integral::testing::*, nat, pos, ConstraintError, Integer};
+
+    use super::{NestedFilter, NestedPoint, Subdivide};
+    use crate::{
+        cycle::{limited_cycle::LimitedCycle, period::Period, simple_cycle::SimpleCycle},
+        filter::simple::SimpleFilter,
+        mixed::{simple::SimpleMixed, types::MixedPoint},
+        IdentityPeriodRepresentation,
+    };
+
+    type Rep = IdentityPeriodRepresentation<i64>;
+    type Years = SimpleFilter<Rep, LimitedCycle<i64>, 1>;
+    type Halves = SimpleFilter<Rep, LimitedCycle<i64>, 1>;
+    type Weeks = SimpleMixed<Rep, SimpleCycle<i64>, 1>;
+
+    // Leap years inside a four year cycle, then uneven halves inside each year
+    // and weeks inside each half
+    fn calendar() -> NestedFilter<Years, NestedFilter<Halves, Weeks>> {
+        NestedFilter::new(
+            SimpleFilter::new(Period::new(pos!(1461)), [LimitedCycle::new(pos!(365), P3)]),
+            NestedFilter::new(
+                SimpleFilter::new(Period::new(pos!(366)), [LimitedCycle::new(pos!(183), P1)]),
+                SimpleMixed::from_factors([SimpleCycle::new(P7)]),
+            ),
+        )
+    }
+
+    #[test]
+    fn nested_wind() {
+        let calendar = calendar();
+        let point = calendar.wind(int!(1460));
+        assert_eq!(MixedPoint::new(I0, [N3]), point.outer);
+        assert_eq!(MixedPoint::new(I0, [N1]), point.inner.outer);
+        assert_eq!(MixedPoint::new(int!(26), [N0]), point.inner.inner);
+
+        let point = calendar.wind(int!(-1));
+        assert_eq!(
+            NestedPoint::new(
+                MixedPoint::new(-I1, [N3]),
+                NestedPoint::new(MixedPoint::new(I0, [N1]), MixedPoint::new(int!(26), [N0]))
+            ),
+            point
+        );
+
+        let point = calendar.wind(int!(364));
+        assert_eq!(MixedPoint::new(I0, [N0]), point.outer);
+        assert_eq!(MixedPoint::new(I0, [N1]), point.inner.outer);
+        assert_eq!(MixedPoint::new(int!(25), [nat!(6)]), point.inner.inner);
+    }
+
+    #[test]
+    fn nested_round_trip() {
+        let calendar = calendar();
+        for k in -3000..3000 {
+            assert_eq!(Ok(int!(k)), calendar.unwind(&calendar.wind(int!(k))));
+        }
+    }
+
+    #[test]
+    fn subdivide_remainder() {
+        let halves = calendar().inner;
+        let point = halves.subdivide(nat!(200));
+        assert_eq!(MixedPoint::new(I0, [N1]), point.outer);
+        assert_eq!(MixedPoint::new(I2, [N3]), point.inner);
+        assert_eq!(Ok(nat!(200)), halves.recombine(&point));
+        let before = MixedPoint::new(-I1, [N3]);
+        assert_eq!(Err(ConstraintError::Negative), halves.inner.recombine(&before));
+    }
+}
@@ -1,57 +1,162 @@
-use derive_more::Constructor;
-use numburs::{HasZero, Integer, Natural};
+use core::fmt::Debug;
+
+use numburs::{Integer, Natural};
 
 use super::types::{Filter, FilterPoint};
 use crate::{
     cycle::{
-        period::{Period, PeriodRepresentation},
+        cycle_kind::CycleKind,
+        period::{IdentityPeriodRepresentation, Period, PeriodRepresentation, WideningPeriodRepresentation},
         types::{Cycle, CyclePoint},
     },
+    place_values::FilterPlaceValues,
+    trace::Trace,
     types::Factors,
-    MixedPoint,
 };
 
-#[derive(PartialEq, Eq, Constructor)]
-pub struct SimpleFilter<R: PeriodRepresentation, C: Cycle<R::Phase, Cycles = Natural<R::Phase>>, const N: usize> {
-    pub period: Period<R>,
-    pub factors: Factors<C, N>,
+// Like `SimpleMixed`, winds through the place values computed when built
+#[derive(PartialEq, Eq, Clone)]
+pub struct SimpleFilter<R: PeriodRepresentation, C: Cycle<R::Cycles, Cycles = Natural<R::Cycles>>, const N: usize> {
+    pub(crate) period: Period<R>,
+    pub(crate) factors: Factors<C, N>,
+    places: FilterPlaceValues<R, N>,
+}
+
+impl<R, C, const N: usize> SimpleFilter<R, C, N>
+where
+    R: PeriodRepresentation,
+    C: Cycle<R::Cycles, Cycles = Natural<R::Cycles>>,
+{
+    pub fn new(period: Period<R>, factors: Factors<C, N>) -> Self {
+        let places = FilterPlaceValues::new(&period, &factors);
+        Self {
+            period,
+            factors,
+            places,
+        }
+    }
+
+    pub fn period(&self) -> &Period<R> {
+        &self.period
+    }
+
+    pub fn factors(&self) -> &Factors<C, N> {
+        &self.factors
+    }
+
+    pub fn place_values(&self) -> &FilterPlaceValues<R, N> {
+        &self.places
+    }
+}
+
+macro_rules! const_from_kinds {
+    ($($t:ty),+) => {
+        $(
+            const_from_kinds!(@rep IdentityPeriodRepresentation<$t>, $t);
+        )+
+    };
+    (widening: $($p:ty => $c:ty),+) => {
+        $(
+            const_from_kinds!(@rep WideningPeriodRepresentation<$p, $c>, $c);
+        )+
+    };
+    (@rep $rep:ty, $t:ty) => {
+        impl<const N: usize> SimpleFilter<$rep, CycleKind<$t>, N> {
+            pub const fn from_kinds(period: Period<$rep>, factors: Factors<CycleKind<$t>, N>) -> Self {
+                let mut sizes: [$t; N] = [1; N];
+                let mut limits = [None; N];
+                let mut k = 0;
+                while k < N {
+                    match &factors[k] {
+                        CycleKind::Simple(cycle) => sizes[k] = cycle.size.out_const(),
+                        CycleKind::Limited(cycle) => {
+                            sizes[k] = cycle.size.out_const();
+                            limits[k] = Some(cycle.limit.out_const());
+                        }
+                    }
+                    k += 1;
+                }
+                let places = FilterPlaceValues::<$rep, N>::from_parts(period.size.out_const(), sizes, limits);
+                Self {
+                    period,
+                    factors,
+                    places,
+                }
+            }
+        }
+    };
+}
+
+const_from_kinds!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+const_from_kinds!(widening: u8 => i64, u16 => i64, u32 => i64, u8 => i128, u16 => i128, u32 => i128);
+
+impl<R, C, const N: usize> Debug for SimpleFilter<R, C, N>
+where
+    R: PeriodRepresentation + Debug,
+    R::Cycles: Debug,
+    C: Cycle<R::Cycles, Cycles = Natural<R::Cycles>> + Debug,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SimpleFilter")
+            .field("period", &self.period)
+            .field("factors", &self.factors)
+            .finish()
+    }
 }
 
 impl<R, const N: usize, C> Filter<N> for SimpleFilter<R, C, N>
 where
     R: PeriodRepresentation,
-    C: Cycle<R::Phase, Cycles = Natural<R::Phase>>,
+    C: Cycle<R::Cycles, Cycles = Natural<R::Cycles>>,
 {
     type Cycle = Integer<R::Cycles>;
     type U = R::Phase;
+    type L = R::Cycles;
 
-    fn is_norm(&self, _point: &FilterPoint<Self::U, N, Self::Cycle>) -> bool {
-        // TODO: implement
-        true
+    fn is_norm(&self, point: &FilterPoint<Self::U, N, Self::Cycle>) -> bool {
+        let FilterPoint { point, remainder } = point;
+        let mut phase = R::embed(*remainder);
+        for k in (0..N).rev() {
+            let cycle_point = CyclePoint::new(R::embed(point.phase[N - k - 1]), phase);
+            if !self.factors[k].is_norm(&cycle_point) {
+                return false;
+            }
+            phase = self.factors[k].unwind(&cycle_point);
+        }
+        phase < self.period.size().into()
     }
 
     fn wind_inner(&self, total: &Self::Cycle) -> FilterPoint<Self::U, N, Self::Cycle> {
-        let CyclePoint { cycle, mut phase } = self.period.wind(total).point;
-        let mut phases = [Natural::ZERO; N];
-        for k in 0..N {
-            let point = self.factors[k].wind(&phase).point;
-            phases[N - k - 1] = point.cycle;
-            phase = point.phase;
-        }
+        self.places.wind_inner(total)
+    }
+
+    fn unwind(&self, point: &FilterPoint<Self::U, N, Self::Cycle>) -> Self::Cycle {
+        self.places.unwind(point)
+    }
 
-        FilterPoint::new(MixedPoint::new(cycle, phases), phase)
+    fn unwind_trace(&self, point: &FilterPoint<Self::U, N, Self::Cycle>) -> Trace<Self::L, Self::Cycle> {
+        let FilterPoint { point, remainder } = point;
+        let mut phase = R::embed(*remainder);
+        let mut factors = Vec::with_capacity(N);
+        for k in (0..N).rev() {
+            let step = self.factors[k].unwind_trace(CyclePoint::new(R::embed(point.phase[N - k - 1]), phase));
+            phase = step.total;
+            factors.push(step);
+        }
+        factors.reverse();
+        Trace::new(self.period.unwind_trace(CyclePoint::new(point.cycle, phase)), factors)
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use numburs::{int, nat, pos, Integer, LowerBoundedRep, Natural, Positive};
+    use numburs::{int, nat, pos, ConstrainedRep, Integer};
 
     use super::SimpleFilter;
     use crate::{
-        cycle::{limited_cycle::LimitedCycle, period::Period, simple_cycle::SimpleCycle},
+        cycle::{cycle_kind::CycleKind, limited_cycle::LimitedCycle, period::Period, simple_cycle::SimpleCycle},
         filter::types::{Filter, FilterPoint},
-        IdentityPeriodRepresentation, MixedPoint,
+        IdentityPeriodRepresentation, MixedPoint, WideningPeriodRepresentation,
     };
 
     fn filter_point(c: i32, p0: i32, p1: i32, r: i32) -> FilterPoint<i32, 2, Integer<i32>> {
@@ -78,6 +183,56 @@ mod tests {
         assert_eq!(filter_point(-1, 2, 1, 1), point(-1));
     }
 
+    #[test]
+    fn filter_unwind() {
+        let filter = SimpleFilter::new(
+            Period::<IdentityPeriodRepresentation<i32>>::new(pos!(50)),
+            [
+                LimitedCycle::new(pos!(10), pos!(3)),
+                LimitedCycle::new(pos!(3), pos!(2)),
+            ],
+        );
+        for k in -60..110 {
+            assert_eq!(int!(k), filter.unwind(&filter.wind(int!(k)).point));
+        }
+    }
+
+    #[test]
+    fn filter_norm() {
+        let filter = SimpleFilter::new(
+            Period::<IdentityPeriodRepresentation<i32>>::new(pos!(50)),
+            [
+                LimitedCycle::new(pos!(10), pos!(3)),
+                LimitedCycle::new(pos!(3), pos!(2)),
+            ],
+        );
+        for k in 0..50 {
+            assert!(filter.is_norm(&filter.wind(int!(k)).point));
+        }
+        assert!(filter.point(int!(0), [nat!(2), nat!(3)], nat!(13)).is_some());
+        assert!(filter.point(int!(0), [nat!(2), nat!(3)], nat!(14)).is_none());
+        assert!(filter.point(int!(0), [nat!(3), nat!(0)], nat!(0)).is_none());
+        assert!(filter.point(int!(0), [nat!(1), nat!(1)], nat!(3)).is_none());
+    }
+
+    #[test]
+    fn filter_trace() {
+        let filter = SimpleFilter::new(
+            Period::<IdentityPeriodRepresentation<i32>>::new(pos!(50)),
+            [
+                LimitedCycle::new(pos!(10), pos!(3)),
+                LimitedCycle::new(pos!(3), pos!(2)),
+            ],
+        );
+        let trace = filter.wind_trace(&int!(99));
+        assert_eq!(
+            "period: I(99) = I(1) * P(50) + N(49)\nfactor 0: N(49) = N(3) * P(10) + N(19) (clamped)\nfactor 1: N(19) = N(2) * P(3) + N(13) (clamped)",
+            trace.to_string()
+        );
+        assert_eq!(trace, filter.unwind_trace(&filter.wind(int!(99)).point));
+        assert!(filter.wind_trace(&int!(25)).factors.iter().all(|step| !step.clamped));
+    }
+
     #[test]
     fn example_limit_filter_scheme() {
         let filter = SimpleFilter::new(
@@ -102,4 +257,42 @@ mod tests {
         assert_eq!(filter_point(-1, 2, 3, 0), point(-14));
         assert_eq!(filter_point(-1, 1, 3, 2), point(-15));
     }
+
+    #[test]
+    fn widening_filter() {
+        let factors = |size: i64, limit: i64| LimitedCycle::new(pos!(size), pos!(limit));
+        let narrow = SimpleFilter::new(
+            Period::<WideningPeriodRepresentation<u8, i64>>::new(pos!(50)),
+            [factors(10, 3), factors(3, 2)],
+        );
+        let wide = SimpleFilter::new(
+            Period::<IdentityPeriodRepresentation<i32>>::new(pos!(50)),
+            [
+                LimitedCycle::new(pos!(10), pos!(3)),
+                LimitedCycle::new(pos!(3), pos!(2)),
+            ],
+        );
+        for k in -120..120 {
+            let FilterPoint { point, remainder } = narrow.wind(int!(k as i64)).point;
+            let [p0, p1] = point.phase.map(|d| i32::from(d.out()));
+            let narrowed = filter_point(point.cycle.out() as i32, p0, p1, i32::from(remainder.out()));
+            assert_eq!(wide.wind(int!(k)).point, narrowed);
+            assert_eq!(int!(k as i64), narrow.unwind(&narrow.wind(int!(k as i64)).point));
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "digit does not fit in the phase type")]
+    fn widening_filter_overflow() {
+        // The remainder of the last year reaches 366, past u8
+        let narrow = SimpleFilter::new(
+            Period::<WideningPeriodRepresentation<u8, i64>>::new(pos!(93502)),
+            [
+                CycleKind::simple(pos!(5844)),
+                CycleKind::simple(pos!(2922)),
+                CycleKind::limited(pos!(365), pos!(7)),
+            ],
+        );
+        narrow.wind(int!(2921));
+    }
 }
@@ -3,9 +3,9 @@ use core::fmt::Debug;
 use derive_more::Constructor;
 use numburs::{Natural, NumBase};
 
-use crate::{mixed::types::MixedPoint, types::Phase};
+use crate::{mixed::types::MixedPoint, trace::Trace, types::Phase};
 
-#[derive(Debug, PartialEq, Eq, Constructor)]
+#[derive(Debug, PartialEq, Eq, Constructor, Clone)]
 pub struct FilterPoint<U: NumBase, const N: usize, Cycle> {
     pub point: MixedPoint<U, N, Cycle>,
     pub remainder: Natural<U>,
@@ -20,9 +20,22 @@ pub struct BoundFilterPoint<'a, const N: usize, P: Filter<N>> {
 pub trait Filter<const N: usize>: Sized {
     type Cycle;
     type U: NumBase;
+    // The type of sizes and the remainder passed between factors, which may
+    // be wider than the digits
+    type L: NumBase;
 
     fn is_norm(&self, point: &FilterPoint<Self::U, N, Self::Cycle>) -> bool;
     fn wind_inner(&self, total: &Self::Cycle) -> FilterPoint<Self::U, N, Self::Cycle>;
+    // No default, since undoing wind_inner without knowing the factors would
+    // mean searching the totals
+    fn unwind(&self, point: &FilterPoint<Self::U, N, Self::Cycle>) -> Self::Cycle;
+
+    fn unwind_trace(&self, point: &FilterPoint<Self::U, N, Self::Cycle>) -> Trace<Self::L, Self::Cycle>;
+
+    // Traces the point that `total` winds to
+    fn wind_trace(&self, total: &Self::Cycle) -> Trace<Self::L, Self::Cycle> {
+        self.unwind_trace(&self.wind_inner(total))
+    }
 
     fn point(
         &self,
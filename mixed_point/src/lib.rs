@@ -1,11 +1,36 @@
+pub mod checked;
+pub mod coincidence;
 pub mod cycle;
 pub mod filter;
 pub mod mixed;
+pub mod offset;
+pub mod owned;
+pub mod place_values;
+pub mod serialization;
+pub mod system;
+pub mod trace;
 pub mod types;
+pub mod validation;
 
 pub use crate::{
+    checked::*,
+    coincidence::*,
     cycle::*,
     filter::*,
-    mixed::{simple::*, types::*},
+    mixed::{balanced::*, pattern::*, projection::*, simple::*, types::*},
+    offset::*,
+    owned::*,
+    place_values::*,
+    serialization::{DefinitionError, MixedPointSchema, MixedPointWithLegend},
+    trace::*,
     types::*,
+    validation::*,
 };
+
+// Used by `mixed_system!`
+#[doc(hidden)]
+pub use numburs;
+#[doc(hidden)]
+pub use paste;
+#[doc(hidden)]
+pub use serde;
@@ -0,0 +1,185 @@
+use core::fmt::{self, Display};
+
+use derive_more::Constructor;
+use num_traits::{ConstOne, ConstZero};
+use numburs::{ConstrainedRep, HasZero, Integer, NumBase};
+
+use super::{
+    simple::SimpleMixed,
+    types::{BoundMixedPoint, Mixed, MixedPoint, MixedPointLegend},
+};
+use crate::cycle::{period::PeriodRepresentation, simple_cycle::SimpleCycle, types::Cycle};
+
+// A point whose digits may be negative, read as cycle * period plus each
+// digit times its place value. Digits lie in (-size/2, size/2], so odd sizes
+// are symmetric around zero and even sizes lean positive.
+#[derive(Debug, PartialEq, Eq, Constructor, Clone, Copy)]
+pub struct BalancedPoint<U: NumBase, const N: usize> {
+    pub cycle: Integer<U>,
+    pub digits: [Integer<U>; N],
+}
+
+// The balanced counterpart of a mixed system, winding totals to the point
+// nearest zero in each digit rather than to a non-negative phase. It does not
+// implement Mixed, whose phases are Natural digits, since balanced digits are
+// signed.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct BalancedMixed<R: PeriodRepresentation, const N: usize> {
+    pub mixed: SimpleMixed<R, SimpleCycle<R::Cycles>, N>,
+}
+
+impl<R: PeriodRepresentation, const N: usize> BalancedMixed<R, N> {
+    pub const fn new(mixed: SimpleMixed<R, SimpleCycle<R::Cycles>, N>) -> Self {
+        Self { mixed }
+    }
+
+    fn size(&self, k: usize) -> R::Cycles {
+        self.mixed.factors[k].size().out()
+    }
+
+    pub fn is_norm(&self, point: &BalancedPoint<R::Cycles, N>) -> bool {
+        (0..N).all(|k| {
+            // Within (-size/2, size/2], compared without halving odd sizes
+            let (digit, size) = (point.digits[k].out(), self.size(k));
+            let twice = digit + digit;
+            twice <= size && twice > R::Cycles::ZERO - size
+        })
+    }
+
+    pub fn wind(&self, total: Integer<R::Cycles>) -> BalancedPoint<R::Cycles, N> {
+        let mut rest = total;
+        let mut digits = [Integer::ZERO; N];
+        for (k, digit) in digits.iter_mut().enumerate() {
+            let size = self.mixed.factors[k].size();
+            let (cycle, phase) = size.euclid(rest);
+            // Past the middle, borrow from the next digit up
+            if phase.out() > size.out() - phase.out() {
+                rest = Integer::new(cycle.out() + R::Cycles::ONE);
+                *digit = Integer::new(phase.out() - size.out());
+            } else {
+                rest = cycle;
+                *digit = phase.into();
+            }
+        }
+        BalancedPoint::new(rest, digits)
+    }
+
+    pub fn unwind(&self, point: &BalancedPoint<R::Cycles, N>) -> Integer<R::Cycles> {
+        let mut total = point.cycle.out();
+        for k in (0..N).rev() {
+            total = total * self.size(k) + point.digits[k].out();
+        }
+        Integer::new(total)
+    }
+
+    pub fn balance(&self, point: &MixedPoint<R::Phase, N, Integer<R::Cycles>>) -> BalancedPoint<R::Cycles, N> {
+        self.wind(self.mixed.unwind(point))
+    }
+
+    pub fn standard(
+        &self,
+        point: &BalancedPoint<R::Cycles, N>,
+    ) -> BoundMixedPoint<'_, N, SimpleMixed<R, SimpleCycle<R::Cycles>, N>> {
+        self.mixed.wind(self.unwind(point))
+    }
+}
+
+// Writes the non-zero digits highest first with their signs and names, as in
+// "-1 beat +3 moment", or "0" when all are zero
+pub struct BalancedWithLegend<'a, U: NumBase, const N: usize> {
+    pub point: &'a BalancedPoint<U, N>,
+    pub legend: &'a MixedPointLegend<'a, N>,
+}
+
+impl<'a, U: NumBase, const N: usize> BalancedWithLegend<'a, U, N> {
+    pub fn new(point: &'a BalancedPoint<U, N>, legend: &'a MixedPointLegend<'a, N>) -> Self {
+        Self { point, legend }
+    }
+}
+
+impl<U: NumBase + Display, const N: usize> Display for BalancedWithLegend<'_, U, N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let names = self.legend.phase.names();
+        let terms = core::iter::once((self.point.cycle, self.legend.cycle))
+            .chain((0..N).rev().map(|k| (self.point.digits[k], names[k])))
+            .filter(|(value, _)| *value != Integer::ZERO);
+        let mut empty = true;
+        for (value, name) in terms {
+            let sign = if value.out() > U::ZERO { "+" } else { "" };
+            let space = if empty { "" } else { " " };
+            write!(f, "{}{}{} {}", space, sign, value.out(), name)?;
+            empty = false;
+        }
+        if empty {
+            write!(f, "0")?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use numburs::{int, integral::testing::*, Integer};
+
+    use super::{BalancedMixed, BalancedPoint, BalancedWithLegend};
+    use crate::{
+        cycle::{period::IdentityPeriodRepresentation, simple_cycle::SimpleCycle},
+        mixed::{
+            simple::SimpleMixed,
+            types::{Mixed, MixedPointLegend},
+        },
+    };
+
+    type Rep = IdentityPeriodRepresentation<i64>;
+    type MixedType = SimpleMixed<Rep, SimpleCycle<i64>, 3>;
+
+    const BALANCED: BalancedMixed<Rep, 3> = BalancedMixed::new(MixedType::from_sizes([3, 4, 5]));
+
+    const LEGEND: MixedPointLegend<'static, 3> = MixedPointLegend::with_names("lap", ["step", "stride", "run"]);
+
+    #[test]
+    fn balanced_wind() {
+        assert_eq!(BalancedPoint::new(I0, [I0, I0, I0]), BALANCED.wind(I0));
+        assert_eq!(BalancedPoint::new(I0, [I1, I0, I0]), BALANCED.wind(I1));
+        assert_eq!(BalancedPoint::new(I0, [-I1, I1, I0]), BALANCED.wind(I2));
+        assert_eq!(BalancedPoint::new(I0, [-I1, I0, I0]), BALANCED.wind(-I1));
+        assert_eq!(BalancedPoint::new(I0, [I0, I2, I0]), BALANCED.wind(I6));
+        assert_eq!(BalancedPoint::new(I0, [I0, -I1, I0]), BALANCED.wind(-I3));
+        assert_eq!(BalancedPoint::new(-I1, [I0, I0, I0]), BALANCED.wind(int!(-60)));
+        for k in -200..200 {
+            let point = BALANCED.wind(int!(k));
+            assert!(BALANCED.is_norm(&point));
+            assert_eq!(int!(k), BALANCED.unwind(&point));
+        }
+    }
+
+    #[test]
+    fn balanced_is_norm() {
+        assert!(BALANCED.is_norm(&BalancedPoint::new(-I1, [-I1, I2, -I2])));
+        assert!(BALANCED.is_norm(&BalancedPoint::new(I1, [I1, -I1, I2])));
+        assert!(!BALANCED.is_norm(&BalancedPoint::new(I0, [I2, I0, I0])));
+        assert!(!BALANCED.is_norm(&BalancedPoint::new(I0, [-I2, I0, I0])));
+        assert!(!BALANCED.is_norm(&BalancedPoint::new(I0, [I0, -I2, I0])));
+        assert!(!BALANCED.is_norm(&BalancedPoint::new(I0, [I0, I3, I0])));
+        assert!(!BALANCED.is_norm(&BalancedPoint::new(I0, [I0, I0, -I3])));
+    }
+
+    #[test]
+    fn conversions() {
+        for k in -100..100 {
+            let standard = BALANCED.mixed.wind(int!(k));
+            let balanced = BALANCED.balance(&standard.point);
+            assert_eq!(BALANCED.wind(int!(k)), balanced);
+            assert_eq!(standard, BALANCED.standard(&balanced));
+        }
+    }
+
+    #[test]
+    fn balanced_display() {
+        let show = |k: i64| BalancedWithLegend::new(&BALANCED.wind(int!(k)), &LEGEND).to_string();
+        assert_eq!("0", show(0));
+        assert_eq!("-1 stride +1 step", show(-2));
+        assert_eq!("+1 lap -1 step", show(59));
+        assert_eq!("-2 run +1 stride", show(-21));
+    }
+}
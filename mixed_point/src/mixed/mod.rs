@@ -1,5 +1,11 @@
+pub mod balanced;
+pub mod pattern;
+pub mod projection;
 pub mod simple;
 pub mod types;
 
+pub use balanced::*;
+pub use pattern::*;
+pub use projection::*;
 pub use simple::*;
 pub use types::*;
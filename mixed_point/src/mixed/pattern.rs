@@ -0,0 +1,259 @@
+use num_traits::{ConstOne, ConstZero};
+use numburs::{ConstrainedRep, Integer, Natural, NumBase, Positive};
+
+use super::{
+    simple::SimpleMixed,
+    types::{Mixed, MixedPoint},
+};
+use crate::{
+    cycle::{period::PeriodRepresentation, types::Cycle},
+    types::Phase,
+};
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum DigitPattern<U: NumBase> {
+    Any,
+    Exact(Natural<U>),
+    Set(Vec<Natural<U>>),
+    // Every `step` digits from `start`, so `*/2` is a start of 0 and a step of 2
+    Step { start: Natural<U>, step: Positive<U> },
+}
+
+impl<U: NumBase> DigitPattern<U> {
+    pub fn matches(&self, digit: Natural<U>) -> bool {
+        match self {
+            DigitPattern::Any => true,
+            DigitPattern::Exact(value) => digit == *value,
+            DigitPattern::Set(values) => values.contains(&digit),
+            DigitPattern::Step { start, step } => {
+                digit >= *start && (digit.out() - start.out()) % step.out() == U::ZERO
+            }
+        }
+    }
+
+    // The least matching digit in [from, last]
+    fn next_from(&self, from: U, last: U) -> Option<U> {
+        let found = match self {
+            DigitPattern::Any => Some(from),
+            DigitPattern::Exact(value) => Some(value.out()).filter(|v| *v >= from),
+            DigitPattern::Set(values) => values.iter().map(|v| v.out()).filter(|v| *v >= from).min(),
+            DigitPattern::Step { start, step } => {
+                let (start, step) = (start.out(), step.out());
+                if from <= start {
+                    Some(start)
+                } else {
+                    let steps = (from - start + step - U::ONE) / step;
+                    Some(start + steps * step)
+                }
+            }
+        };
+        found.filter(|v| *v <= last)
+    }
+
+    // The greatest matching digit in [0, from]
+    fn previous_from(&self, from: U) -> Option<U> {
+        match self {
+            DigitPattern::Any => Some(from),
+            DigitPattern::Exact(value) => Some(value.out()).filter(|v| *v <= from),
+            DigitPattern::Set(values) => values.iter().map(|v| v.out()).filter(|v| *v <= from).max(),
+            DigitPattern::Step { start, step } => {
+                let (start, step) = (start.out(), step.out());
+                if from < start {
+                    None
+                } else {
+                    Some(start + (from - start) / step * step)
+                }
+            }
+        }
+    }
+}
+
+// A pattern for each digit of a phase, lowest first
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct PhasePattern<U: NumBase, const N: usize>(pub [DigitPattern<U>; N]);
+
+impl<U: NumBase, const N: usize> PhasePattern<U, N> {
+    pub fn new(digits: [DigitPattern<U>; N]) -> Self {
+        Self(digits)
+    }
+
+    pub fn matches(&self, phase: &Phase<U, N>) -> bool {
+        self.0
+            .iter()
+            .zip(phase.iter())
+            .all(|(digit, value)| digit.matches(*value))
+    }
+}
+
+impl<R, C, const N: usize> SimpleMixed<R, C, N>
+where
+    R: PeriodRepresentation,
+    C: Cycle<R::Cycles, Cycles = Natural<R::Cycles>>,
+{
+    // The greatest digit at level `k`, which fits the phase type even when
+    // the size does not
+    fn last_digit(&self, k: usize) -> R::Phase {
+        R::project(Natural::new_unchecked(self.factors[k].size().out() - R::Cycles::ONE)).out()
+    }
+
+    fn is_satisfiable(&self, pattern: &PhasePattern<R::Phase, N>) -> bool {
+        (0..N).all(|k| pattern.0[k].next_from(R::Phase::ZERO, self.last_digit(k)).is_some())
+    }
+
+    // Fills the digits below `level` with the nearest match in the direction
+    // of the search. While `tight`, the digits must not pass those in `phase`.
+    fn seek(
+        &self,
+        pattern: &PhasePattern<R::Phase, N>,
+        phase: &mut Phase<R::Phase, N>,
+        level: usize,
+        tight: bool,
+        forward: bool,
+    ) -> bool {
+        if level == 0 {
+            return true;
+        }
+        let k = level - 1;
+        let last = self.last_digit(k);
+        let mut from = match (tight, forward) {
+            (true, _) => phase[k].out(),
+            (false, true) => R::Phase::ZERO,
+            (false, false) => last,
+        };
+        loop {
+            let found = if forward {
+                pattern.0[k].next_from(from, last)
+            } else {
+                pattern.0[k].previous_from(from)
+            };
+            let Some(digit) = found else {
+                return false;
+            };
+            let still_tight = tight && digit == phase[k].out();
+            phase[k] = Natural::new_unchecked(digit);
+            if self.seek(pattern, phase, k, still_tight, forward) {
+                return true;
+            }
+            if forward && digit < last {
+                from = digit + R::Phase::ONE;
+            } else if !forward && digit > R::Phase::ZERO {
+                from = digit - R::Phase::ONE;
+            } else {
+                return false;
+            }
+        }
+    }
+
+    fn find(
+        &self,
+        pattern: &PhasePattern<R::Phase, N>,
+        total: Integer<R::Cycles>,
+        forward: bool,
+    ) -> Option<Integer<R::Cycles>> {
+        if !self.is_satisfiable(pattern) {
+            return None;
+        }
+        let MixedPoint { cycle, mut phase } = self.wind_inner(&total);
+        if self.seek(pattern, &mut phase, N, true, forward) {
+            return Some(self.unwind(&MixedPoint::new(cycle, phase)));
+        }
+        let cycle = if forward {
+            Integer::new(cycle.out() + R::Cycles::ONE)
+        } else {
+            Integer::new(cycle.out() - R::Cycles::ONE)
+        };
+        self.seek(pattern, &mut phase, N, false, forward);
+        Some(self.unwind(&MixedPoint::new(cycle, phase)))
+    }
+
+    // The first total at or after `total` whose phase matches the pattern
+    pub fn next_match(
+        &self,
+        pattern: &PhasePattern<R::Phase, N>,
+        total: Integer<R::Cycles>,
+    ) -> Option<Integer<R::Cycles>> {
+        self.find(pattern, total, true)
+    }
+
+    // The last total at or before `total` whose phase matches the pattern
+    pub fn previous_match(
+        &self,
+        pattern: &PhasePattern<R::Phase, N>,
+        total: Integer<R::Cycles>,
+    ) -> Option<Integer<R::Cycles>> {
+        self.find(pattern, total, false)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use numburs::{int, integral::testing::*, Integer};
+
+    use super::{DigitPattern, PhasePattern};
+    use crate::{
+        cycle::{period::IdentityPeriodRepresentation, simple_cycle::SimpleCycle},
+        mixed::{simple::SimpleMixed, types::Mixed},
+    };
+
+    type MixedType = SimpleMixed<IdentityPeriodRepresentation<i64>, SimpleCycle<i64>, 3>;
+
+    const MIXED: MixedType = MixedType::from_sizes([2, 3, 4]);
+
+    fn patterns() -> Vec<PhasePattern<i64, 3>> {
+        vec![
+            PhasePattern::new([DigitPattern::Any, DigitPattern::Any, DigitPattern::Any]),
+            PhasePattern::new([
+                DigitPattern::Exact(N1),
+                DigitPattern::Any,
+                DigitPattern::Step { start: N0, step: P2 },
+            ]),
+            PhasePattern::new([
+                DigitPattern::Any,
+                DigitPattern::Set(vec![N2, N0]),
+                DigitPattern::Exact(N3),
+            ]),
+            PhasePattern::new([
+                DigitPattern::Exact(N0),
+                DigitPattern::Step { start: N1, step: P3 },
+                DigitPattern::Set(vec![N1, N2]),
+            ]),
+        ]
+    }
+
+    fn matches(pattern: &PhasePattern<i64, 3>, total: i64) -> bool {
+        pattern.matches(&MIXED.wind(int!(total)).point.phase)
+    }
+
+    #[test]
+    fn next_match() {
+        for pattern in patterns() {
+            for k in -60..60 {
+                let expected = (k..).find(|t| matches(&pattern, *t)).map(|t| int!(t));
+                assert_eq!(expected, MIXED.next_match(&pattern, int!(k)));
+            }
+        }
+    }
+
+    #[test]
+    fn previous_match() {
+        for pattern in patterns() {
+            for k in -60..60 {
+                let expected = (k - 24..=k).rev().find(|t| matches(&pattern, *t)).map(|t| int!(t));
+                assert_eq!(expected, MIXED.previous_match(&pattern, int!(k)));
+            }
+        }
+    }
+
+    #[test]
+    fn unsatisfiable() {
+        let pattern = PhasePattern::new([DigitPattern::Exact(N2), DigitPattern::Any, DigitPattern::Any]);
+        assert_eq!(None, MIXED.next_match(&pattern, I0));
+        assert_eq!(None, MIXED.previous_match(&pattern, I0));
+        let pattern = PhasePattern::new([
+            DigitPattern::Any,
+            DigitPattern::Step { start: N4, step: P1 },
+            DigitPattern::Set(vec![]),
+        ]);
+        assert_eq!(None, MIXED.next_match(&pattern, I0));
+    }
+}
@@ -0,0 +1,217 @@
+use derive_more::Constructor;
+use numburs::{ConstrainedRep, HasOne, HasZero, Integer, Natural, NumBase, Positive};
+
+use super::{
+    simple::SimpleMixed,
+    types::{BoundMixedPoint, Mixed, MixedPoint},
+};
+use crate::cycle::{period::PeriodRepresentation, types::Cycle};
+
+// An exact fraction in [0, 1) of a base unit, kept in lowest terms
+#[derive(Debug, PartialEq, Eq, Constructor, Clone, Copy)]
+pub struct Ratio<U: NumBase> {
+    pub numerator: Natural<U>,
+    pub denominator: Positive<U>,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Rounding {
+    Down,
+    Nearest,
+    Up,
+}
+
+#[derive(Debug, PartialEq, Eq, Constructor, Clone)]
+pub struct Projected<X, U: NumBase> {
+    pub point: X,
+    pub remainder: Ratio<U>,
+}
+
+type Landing<U> = (Integer<U>, Integer<U>, Ratio<U>);
+
+pub(crate) fn gcd<U: NumBase>(mut a: U, mut b: U) -> U {
+    while b != U::ZERO {
+        (a, b) = (b, a % b);
+    }
+    a
+}
+
+impl<U: NumBase> Ratio<U> {
+    pub fn reduced(numerator: Natural<U>, denominator: Positive<U>) -> Self {
+        let divisor = gcd(numerator.out(), denominator.out());
+        Self::new(
+            Natural::new_unchecked(numerator.out() / divisor),
+            Positive::new_unchecked(denominator.out() / divisor),
+        )
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.numerator == Natural::ZERO
+    }
+
+    pub fn rounds_up(&self, rounding: Rounding) -> bool {
+        match rounding {
+            Rounding::Down => false,
+            Rounding::Nearest => self.numerator.out() + self.numerator.out() >= self.denominator.out(),
+            Rounding::Up => !self.is_zero(),
+        }
+    }
+}
+
+impl<R, C, const N: usize> SimpleMixed<R, C, N>
+where
+    R: PeriodRepresentation,
+    C: Cycle<R::Cycles, Cycles = Natural<R::Cycles>>,
+{
+    // Splits a point into its cycle, the whole base units of the target
+    // period it covers and the remaining fraction of a target base unit
+    fn project_whole<S, D, const M: usize>(
+        &self,
+        target: &SimpleMixed<S, D, M>,
+        point: &MixedPoint<R::Phase, N, Integer<R::Cycles>>,
+    ) -> Landing<R::Cycles>
+    where
+        S: PeriodRepresentation<Cycles = R::Cycles>,
+        D: Cycle<S::Cycles, Cycles = Natural<S::Cycles>>,
+    {
+        let source_size = self.period.size().out();
+        let target_size = target.period.size().out();
+        let divisor = gcd(source_size, target_size);
+        let scale = Positive::new_unchecked(target_size / divisor);
+        let denominator = Positive::new_unchecked(source_size / divisor);
+
+        let offset = self.unwind(&MixedPoint::new(Integer::ZERO, point.phase));
+        let (whole, remainder) = denominator.euclid(offset * scale);
+        (point.cycle, whole, Ratio::reduced(remainder, denominator))
+    }
+
+    fn land<'b, S, D, const M: usize>(
+        target: &'b SimpleMixed<S, D, M>,
+        cycle: Integer<R::Cycles>,
+        whole: Integer<R::Cycles>,
+    ) -> BoundMixedPoint<'b, M, SimpleMixed<S, D, M>>
+    where
+        S: PeriodRepresentation<Cycles = R::Cycles>,
+        D: Cycle<S::Cycles, Cycles = Natural<S::Cycles>>,
+    {
+        let mut landed = target.wind(whole);
+        landed.point.cycle = landed.point.cycle + cycle;
+        landed
+    }
+
+    pub fn project<'b, S, D, const M: usize>(
+        &self,
+        target: &'b SimpleMixed<S, D, M>,
+        point: &MixedPoint<R::Phase, N, Integer<R::Cycles>>,
+    ) -> Projected<BoundMixedPoint<'b, M, SimpleMixed<S, D, M>>, R::Cycles>
+    where
+        S: PeriodRepresentation<Cycles = R::Cycles>,
+        D: Cycle<S::Cycles, Cycles = Natural<S::Cycles>>,
+    {
+        let (cycle, whole, remainder) = self.project_whole(target, point);
+        Projected::new(Self::land(target, cycle, whole), remainder)
+    }
+
+    pub fn project_rounded<'b, S, D, const M: usize>(
+        &self,
+        target: &'b SimpleMixed<S, D, M>,
+        point: &MixedPoint<R::Phase, N, Integer<R::Cycles>>,
+        rounding: Rounding,
+    ) -> BoundMixedPoint<'b, M, SimpleMixed<S, D, M>>
+    where
+        S: PeriodRepresentation<Cycles = R::Cycles>,
+        D: Cycle<S::Cycles, Cycles = Natural<S::Cycles>>,
+    {
+        let (cycle, whole, remainder) = self.project_whole(target, point);
+        let whole = if remainder.rounds_up(rounding) {
+            whole + Integer::ONE
+        } else {
+            whole
+        };
+        Self::land(target, cycle, whole)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use numburs::{int, integral::testing::*, nat, pos, Integer};
+
+    use super::{Ratio, Rounding};
+    use crate::{
+        cycle::{period::IdentityPeriodRepresentation, simple_cycle::SimpleCycle},
+        mixed::{
+            simple::SimpleMixed,
+            types::{Mixed, MixedPoint},
+        },
+    };
+
+    type MixedType<const N: usize> = SimpleMixed<IdentityPeriodRepresentation<i64>, SimpleCycle<i64>, N>;
+
+    const SIXTEENTHS: MixedType<2> = MixedType::<2>::from_sizes([4, 4]);
+    const SIXTHS: MixedType<2> = MixedType::<2>::from_sizes([3, 2]);
+    const HALVES: MixedType<1> = MixedType::<1>::from_sizes([2]);
+
+    #[test]
+    fn project_incommensurate() {
+        let projected = SIXTEENTHS.project(&SIXTHS, &MixedPoint::new(I0, [N1, N1]));
+        assert_eq!(MixedPoint::new(I0, [N1, N0]), projected.point.point);
+        assert_eq!(Ratio::new(nat!(7), pos!(8)), projected.remainder);
+
+        let projected = SIXTEENTHS.project(&SIXTHS, &MixedPoint::new(I2, [N3, N3]));
+        assert_eq!(MixedPoint::new(I2, [N2, N1]), projected.point.point);
+        assert_eq!(Ratio::new(N5, P8), projected.remainder);
+
+        let projected = SIXTEENTHS.project(&SIXTHS, &MixedPoint::new(-I1, [N0, N2]));
+        assert_eq!(MixedPoint::new(-I1, [N0, N1]), projected.point.point);
+        assert_eq!(Ratio::new(N0, P1), projected.remainder);
+    }
+
+    #[test]
+    fn project_commensurate() {
+        for k in 0..16 {
+            let point = SIXTEENTHS.wind(int!(k)).point;
+            let projected = SIXTEENTHS.project(&HALVES, &point);
+            assert_eq!(MixedPoint::new(I0, [nat!(k / 8)]), projected.point.point);
+            assert_eq!(
+                Ratio::new(nat!((k % 8) / gcd(k % 8, 8)), pos!(8 / gcd(k % 8, 8))),
+                projected.remainder
+            );
+        }
+        let projected = HALVES.project(&SIXTEENTHS, &MixedPoint::new(I3, [N1]));
+        assert_eq!(MixedPoint::new(I3, [N0, N2]), projected.point.point);
+        assert!(projected.remainder.is_zero());
+    }
+
+    #[test]
+    fn project_rounded() {
+        let project = |point, rounding| SIXTEENTHS.project_rounded(&SIXTHS, &point, rounding).point;
+        assert_eq!(
+            MixedPoint::new(I0, [N1, N0]),
+            project(MixedPoint::new(I0, [N1, N1]), Rounding::Down)
+        );
+        assert_eq!(
+            MixedPoint::new(I0, [N2, N0]),
+            project(MixedPoint::new(I0, [N1, N1]), Rounding::Nearest)
+        );
+        assert_eq!(
+            MixedPoint::new(I0, [N2, N0]),
+            project(MixedPoint::new(I0, [N1, N1]), Rounding::Up)
+        );
+        assert_eq!(
+            MixedPoint::new(I0, [N0, N0]),
+            project(MixedPoint::new(I0, [N1, N0]), Rounding::Nearest)
+        );
+        assert_eq!(
+            MixedPoint::new(I0, [N1, N0]),
+            project(MixedPoint::new(I0, [N1, N0]), Rounding::Up)
+        );
+        assert_eq!(
+            MixedPoint::new(I3, [N0, N0]),
+            project(MixedPoint::new(I2, [N3, N3]), Rounding::Nearest)
+        );
+    }
+
+    fn gcd(a: i64, b: i64) -> i64 {
+        super::gcd(a, b)
+    }
+}
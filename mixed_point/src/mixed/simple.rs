@@ -1,26 +1,33 @@
 use core::fmt::Debug;
 
-use derive_more::Constructor;
-use numburs::{HasOne, HasZero, Integer, Natural, Positive};
+use num_traits::{CheckedMul, ConstOne};
+use numburs::{ConstrainedRep, HasOne, HasZero, Integer, Natural, Positive};
 
 use super::types::{Mixed, MixedPoint};
-use crate::cycle::{
-    period::{Period, PeriodRepresentation},
-    simple_cycle::SimpleCycle,
-    types::{Cycle, CyclePoint},
+use crate::{
+    cycle::{
+        period::{IdentityPeriodRepresentation, Period, PeriodRepresentation, WideningPeriodRepresentation},
+        simple_cycle::SimpleCycle,
+        types::{Cycle, CyclePoint},
+    },
+    place_values::PlaceValues,
+    trace::Trace,
 };
 
-#[derive(PartialEq, Eq, Constructor, Clone)]
-pub struct SimpleMixed<R: PeriodRepresentation, C: Cycle<R::Phase, Cycles = Natural<R::Phase>>, const N: usize> {
-    pub period: Period<R>,
-    pub factors: [C; N],
+// Winds through the place values computed when built, which is why the
+// period and factors are only readable
+#[derive(PartialEq, Eq, Clone)]
+pub struct SimpleMixed<R: PeriodRepresentation, C: Cycle<R::Cycles, Cycles = Natural<R::Cycles>>, const N: usize> {
+    pub(crate) period: Period<R>,
+    pub(crate) factors: [C; N],
+    places: PlaceValues<R, N>,
 }
 
 impl<R, C, const N: usize> Debug for SimpleMixed<R, C, N>
 where
     R: PeriodRepresentation + Debug,
-    R::Phase: Debug,
-    C: Cycle<R::Phase, Cycles = Natural<R::Phase>> + Debug,
+    R::Cycles: Debug,
+    C: Cycle<R::Cycles, Cycles = Natural<R::Cycles>> + Debug,
 {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("SimpleMixed")
@@ -33,14 +40,40 @@ where
 impl<R, C, const N: usize> SimpleMixed<R, C, N>
 where
     R: PeriodRepresentation,
-    C: Cycle<R::Phase, Cycles = Natural<R::Phase>>,
+    C: Cycle<R::Cycles, Cycles = Natural<R::Cycles>>,
 {
+    pub fn new(period: Period<R>, factors: [C; N]) -> Self {
+        let places = PlaceValues::new(&period, &factors);
+        Self {
+            period,
+            factors,
+            places,
+        }
+    }
+
+    pub fn period(&self) -> &Period<R> {
+        &self.period
+    }
+
+    pub fn factors(&self) -> &[C; N] {
+        &self.factors
+    }
+
+    pub fn place_values(&self) -> &PlaceValues<R, N> {
+        &self.places
+    }
+
     pub fn from_factors(factors: [C; N]) -> Self {
-        let mut prod = Positive::ONE;
+        Self::checked_from_factors(factors).expect("period overflows the cycle type")
+    }
+
+    // None when the product of the factor sizes does not fit the cycle type
+    pub fn checked_from_factors(factors: [C; N]) -> Option<Self> {
+        let mut prod = R::Cycles::ONE;
         for c in factors.as_slice() {
-            prod = prod * c.size();
+            prod = prod.checked_mul(&c.size().out())?;
         }
-        Self::new(Period::new(prod), factors)
+        Some(Self::new(Period::new(Positive::new_unchecked(prod)), factors))
     }
 
     pub fn num_factors(&self) -> usize {
@@ -48,26 +81,64 @@ where
     }
 }
 
-impl<R, const N: usize> SimpleMixed<R, SimpleCycle<R::Phase>, N>
+impl<R, const N: usize> SimpleMixed<R, SimpleCycle<R::Cycles>, N>
 where
     R: PeriodRepresentation,
 {
-    pub fn from_simple_factors(sizes: [Positive<R::Phase>; N]) -> Self {
+    pub fn from_simple_factors(sizes: [Positive<R::Cycles>; N]) -> Self {
         Self::from_factors(sizes.map(SimpleCycle::new))
     }
 }
 
+macro_rules! const_from_sizes {
+    ($($t:ty),+) => {
+        $(
+            const_from_sizes!(@rep IdentityPeriodRepresentation<$t>, $t, $t);
+        )+
+    };
+    (widening: $($p:ty => $c:ty),+) => {
+        $(
+            const_from_sizes!(@rep WideningPeriodRepresentation<$p, $c>, $p, $c);
+        )+
+    };
+    (@rep $rep:ty, $p:ty, $t:ty) => {
+        impl<const N: usize> SimpleMixed<$rep, SimpleCycle<$t>, N> {
+            pub const fn from_sizes(sizes: [$t; N]) -> Self {
+                let mut prod = Positive::<$t>::ONE;
+                let mut factors = [SimpleCycle::new(Positive::<$t>::ONE); N];
+                let mut k = 0;
+                while k < N {
+                    let size = Positive::<$t>::new_const(sizes[k]);
+                    assert!(sizes[k] - 1 <= <$p>::MAX as $t, "digits of a factor do not fit in the phase type");
+                    factors[k] = SimpleCycle::new(size);
+                    prod = prod.const_mul(size);
+                    k += 1;
+                }
+                Self {
+                    period: Period::new(prod),
+                    factors,
+                    places: PlaceValues::<$rep, N>::from_parts(prod.out_const(), sizes, [None; N]),
+                }
+            }
+        }
+    };
+}
+
+const_from_sizes!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+const_from_sizes!(widening: u8 => i64, u16 => i64, u32 => i64, u8 => i128, u16 => i128, u32 => i128);
+
 impl<R, const N: usize, C> Mixed<N> for SimpleMixed<R, C, N>
 where
     R: PeriodRepresentation,
-    C: Cycle<R::Phase, Cycles = Natural<R::Phase>>,
+    C: Cycle<R::Cycles, Cycles = Natural<R::Cycles>>,
 {
     type Cycles = Integer<R::Cycles>;
     type U = R::Phase;
+    type L = R::Cycles;
 
     fn is_norm(&self, point: &MixedPoint<Self::U, N, Self::Cycles>) -> bool {
         for k in 0..N {
-            if Natural::from(self.factors[k].size()) <= point.phase[k] {
+            if Natural::from(self.factors[k].size()) <= R::embed(point.phase[k]) {
                 return false;
             }
         }
@@ -75,41 +146,48 @@ where
     }
 
     fn wind_inner(&self, total: &Self::Cycles) -> MixedPoint<Self::U, N, Self::Cycles> {
-        let CyclePoint { cycle, mut phase } = self.period.wind(total).point;
-        let mut phases = [Natural::ZERO; N];
-        for (k, factor) in self.factors.iter().enumerate() {
-            let point = factor.wind(&phase).point;
-            phases[k] = point.phase;
-            phase = point.cycle;
-        }
+        self.places.wind_inner(total)
+    }
 
-        MixedPoint::new(cycle, phases)
+    fn stride(&self, level: usize) -> Positive<Self::L> {
+        assert!(level <= N, "level {} is past the {} factors", level, N);
+        self.factors.iter().take(level).fold(Positive::ONE, |p, c| p * c.size())
     }
 
     fn unwind(&self, point: &MixedPoint<Self::U, N, Self::Cycles>) -> Self::Cycles {
-        let MixedPoint { cycle, phase } = point;
-        let mut total = *cycle;
+        self.places.unwind(point)
+    }
+
+    fn unwind_trace(&self, point: &MixedPoint<Self::U, N, Self::Cycles>) -> Trace<Self::L, Self::Cycles> {
+        let mut phase = Natural::ZERO;
+        let mut factors = Vec::with_capacity(N);
         for k in (0..N).rev() {
-            total = Period::<R>::new(self.factors[k].size()).unwind(&CyclePoint::new(total, phase[k]));
+            let step = self.factors[k].unwind_trace(CyclePoint::new(phase, R::embed(point.phase[k])));
+            phase = step.total;
+            factors.push(step);
         }
-        total
+        factors.reverse();
+        Trace::new(self.period.unwind_trace(CyclePoint::new(point.cycle, phase)), factors)
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use std::sync::LazyLock;
-
-    use numburs::{integral::testing::*, Integer};
+    use numburs::{integral::testing::*, ConstrainedRep, Fractional, Integer, Natural, Real, WithFraction};
 
     use super::SimpleMixed;
     use crate::{
-        cycle::{period::IdentityPeriodRepresentation, simple_cycle::SimpleCycle},
+        cycle::{
+            period::{IdentityPeriodRepresentation, WideningPeriodRepresentation},
+            simple_cycle::SimpleCycle,
+            types::Cycle,
+        },
         mixed::types::{BoundMixedPoint, Mixed, MixedPoint},
     };
 
-    static MIXED: LazyLock<SimpleMixed<IdentityPeriodRepresentation<i64>, SimpleCycle<i64>, 2>> =
-        LazyLock::new(|| SimpleMixed::from_factors([SimpleCycle::new(P2), SimpleCycle::new(P3)]));
+    type MixedType = SimpleMixed<IdentityPeriodRepresentation<i64>, SimpleCycle<i64>, 2>;
+
+    const MIXED: MixedType = MixedType::from_sizes([2, 3]);
 
     const I11: Integer<i64> = Integer::new(11);
     const I16: Integer<i64> = Integer::new(16);
@@ -123,12 +201,73 @@ mod tests {
         assert_eq!(MixedPoint::new(I1, [N1, N2]), MIXED.wind(I11).point);
     }
 
+    #[test]
+    fn mixed_trace() {
+        let trace = MIXED.wind_trace(&I11);
+        assert_eq!(
+            "period: I(11) = I(1) * P(6) + N(5)\nfactor 0: N(5) = N(2) * P(2) + N(1)\nfactor 1: N(2) = N(0) * P(3) + N(2)",
+            trace.to_string()
+        );
+        assert_eq!(trace, MIXED.unwind_trace(&MIXED.wind(I11).point));
+    }
+
+    #[test]
+    fn const_from_sizes() {
+        assert_eq!(
+            SimpleMixed::from_factors([SimpleCycle::new(P2), SimpleCycle::new(P3)]),
+            MIXED
+        );
+        assert_eq!(P6, MIXED.period.size());
+    }
+
+    fn real_point(
+        cycle: Integer<i64>,
+        phase: [Natural<i64>; 2],
+        fraction: f64,
+    ) -> WithFraction<MixedPoint<i64, 2, Integer<i64>>, f64> {
+        WithFraction::new(MixedPoint::new(cycle, phase), Fractional::try_from(fraction).unwrap())
+    }
+
+    #[test]
+    fn simple_mixed_wind_real() {
+        let wind = |total: f64, level: usize| {
+            let WithFraction { whole, fraction } = MIXED.wind_real_at(level, Real::from(total));
+            WithFraction::new(whole.point, fraction)
+        };
+        assert_eq!(real_point(I1, [N1, N0], 0.5), wind(7.5, 0));
+        assert_eq!(real_point(-I1, [N1, N2], 0.75), wind(-0.25, 0));
+        assert_eq!(real_point(I1, [N0, N0], 0.75), wind(7.5, 1));
+        assert_eq!(real_point(I1, [N0, N0], 0.25), wind(7.5, 2));
+        assert_eq!(real_point(-I1, [N0, N0], 0.875), wind(-0.75, 2));
+        assert_eq!(MIXED.wind_real_at(0, Real::from(7.5)), MIXED.wind_real(Real::from(7.5)));
+    }
+
+    #[test]
+    fn simple_mixed_unwind_real() {
+        assert_eq!(Real::from(7.5), MIXED.unwind_real(&real_point(I1, [N1, N0], 0.5)));
+        assert_eq!(Real::from(-0.25), MIXED.unwind_real(&real_point(-I1, [N1, N2], 0.75)));
+        assert_eq!(
+            Real::from(7.5),
+            MIXED.unwind_real_at(1, &real_point(I1, [N0, N0], 0.75))
+        );
+        assert_eq!(
+            Real::from(-0.75),
+            MIXED.unwind_real_at(2, &real_point(-I1, [N0, N0], 0.875))
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "level 3 is past the 2 factors")]
+    fn wind_real_past_factors() {
+        MIXED.wind_real_at(3, Real::from(7.5));
+    }
+
     #[test]
     fn simple_mixed_bind() {
         let good_point = MixedPoint::new(I1, [N1, N2]);
         let bad_point = MixedPoint::new(I1, [N2, N2]);
         assert_eq!(
-            Some(BoundMixedPoint::new(&*MIXED, good_point.clone())),
+            Some(BoundMixedPoint::new(&MIXED, good_point.clone())),
             MIXED.bind(good_point)
         );
         assert_eq!(None, MIXED.bind(bad_point));
@@ -144,4 +283,30 @@ mod tests {
         assert_eq!(-I1, MIXED.unwind(&MixedPoint::new(-I1, [N1, N2])));
         assert_eq!(-I3, MIXED.unwind(&MixedPoint::new(-I1, [N1, N1])));
     }
+
+    #[test]
+    fn widening_mixed() {
+        type Wide = SimpleMixed<IdentityPeriodRepresentation<i64>, SimpleCycle<i64>, 5>;
+        type Narrow = SimpleMixed<WideningPeriodRepresentation<u8, i64>, SimpleCycle<i64>, 5>;
+        const WIDE: Wide = Wide::from_sizes([2, 64, 64, 8, 16]);
+        const NARROW: Narrow = Narrow::from_sizes([2, 64, 64, 8, 16]);
+        assert_eq!(16, size_of::<MixedPoint<u8, 5, Integer<i64>>>());
+        assert_eq!(48, size_of::<MixedPoint<i64, 5, Integer<i64>>>());
+
+        for total in (-3_000_000..3_000_000).step_by(4099).chain([i64::MIN, i64::MAX]) {
+            let wide = WIDE.wind(Integer::new(total)).point;
+            let narrow = NARROW.wind(Integer::new(total)).point;
+            assert_eq!(wide.cycle, narrow.cycle);
+            assert_eq!(wide.phase.map(|d| d.out()), narrow.phase.map(|d| i64::from(d.out())));
+            if (i64::MIN / 2..i64::MAX / 2).contains(&total) {
+                assert_eq!(Integer::new(total), NARROW.unwind(&narrow));
+            }
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "digits of a factor do not fit in the phase type")]
+    fn widening_digit_overflow() {
+        SimpleMixed::<WideningPeriodRepresentation<u8, i64>, SimpleCycle<i64>, 2>::from_sizes([257, 2]);
+    }
 }
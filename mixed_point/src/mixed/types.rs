@@ -1,9 +1,12 @@
 use core::fmt::Debug;
 
 use derive_more::Constructor;
-use numburs::NumBase;
+use numburs::{ConstrainedRep, FloatBase, Integer, NumBase, Positive, Real, WithFraction};
 
-use crate::types::{Phase, PhaseLegend};
+use crate::{
+    trace::Trace,
+    types::{OwnedPhaseLegend, Phase, PhaseLegend},
+};
 
 #[derive(Debug, PartialEq, Eq, Constructor, Clone)]
 pub struct MixedPoint<U: NumBase, const N: usize, Cycles> {
@@ -23,7 +26,7 @@ pub struct BoundMixedPoint<'a, const N: usize, P: Mixed<N>> {
     pub point: MixedPoint<P::U, N, P::Cycles>,
 }
 
-#[derive(Debug, PartialEq, Eq, Constructor, Clone)]
+#[derive(Debug, PartialEq, Eq, Clone)]
 pub struct MixedPointLegend<'a, const N: usize> {
     pub cycle: &'a str,
     pub phase: PhaseLegend<'a, N>,
@@ -36,7 +39,11 @@ impl<'a, const N: usize, P: Mixed<N>> BoundMixedPoint<'a, N, P> {
 }
 
 impl<'a, const N: usize> MixedPointLegend<'a, N> {
-    pub fn with_names(cycle: &'a str, phase: [&'a str; N]) -> Self {
+    pub const fn new(cycle: &'a str, phase: PhaseLegend<'a, N>) -> Self {
+        Self { cycle, phase }
+    }
+
+    pub const fn with_names(cycle: &'a str, phase: [&'a str; N]) -> Self {
         Self {
             cycle,
             phase: PhaseLegend::new(phase),
@@ -44,13 +51,37 @@ impl<'a, const N: usize> MixedPointLegend<'a, N> {
     }
 }
 
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct OwnedMixedPointLegend<const N: usize> {
+    pub cycle: String,
+    pub phase: OwnedPhaseLegend<N>,
+}
+
+impl<const N: usize> OwnedMixedPointLegend<N> {
+    pub fn as_legend(&self) -> MixedPointLegend<'_, N> {
+        MixedPointLegend::new(&self.cycle, self.phase.as_legend())
+    }
+}
+
 pub trait Mixed<const N: usize>: Sized {
     type Cycles;
     type U: NumBase;
+    // The type of sizes and place values, which may be wider than the digits
+    type L: NumBase;
 
     fn is_norm(&self, point: &MixedPoint<Self::U, N, Self::Cycles>) -> bool;
     fn wind_inner(&self, total: &Self::Cycles) -> MixedPoint<Self::U, N, Self::Cycles>;
     fn unwind(&self, point: &MixedPoint<Self::U, N, Self::Cycles>) -> Self::Cycles;
+    // The number of totals in one unit of the digit at `level`, up to the
+    // whole period at level N. Panics for levels past N.
+    fn stride(&self, level: usize) -> Positive<Self::L>;
+
+    fn unwind_trace(&self, point: &MixedPoint<Self::U, N, Self::Cycles>) -> Trace<Self::L, Self::Cycles>;
+
+    // Traces the point that `total` winds to
+    fn wind_trace(&self, total: &Self::Cycles) -> Trace<Self::L, Self::Cycles> {
+        self.unwind_trace(&self.wind_inner(total))
+    }
 
     fn bind(&self, point: MixedPoint<Self::U, N, Self::Cycles>) -> Option<BoundMixedPoint<N, Self>> {
         Some(point)
@@ -65,4 +96,44 @@ pub trait Mixed<const N: usize>: Sized {
     fn wind(&self, total: Self::Cycles) -> BoundMixedPoint<N, Self> {
         BoundMixedPoint::new(self, self.wind_inner(&total))
     }
+
+    // Winds a real total counted in units of the digit at `level`, leaving
+    // the part below that digit as the fraction
+    fn wind_real_at<F: FloatBase>(&self, level: usize, total: Real<F>) -> WithFraction<BoundMixedPoint<'_, N, Self>, F>
+    where
+        Self::Cycles: From<Integer<Self::L>>,
+    {
+        let stride = self.stride(level);
+        let scaled = total.out() / F::from(stride.out()).expect("stride does not fit in the float type");
+        let WithFraction { whole, fraction } = Real::from(scaled).fractionalize();
+        WithFraction::new(self.wind((whole * stride).into()), fraction)
+    }
+
+    fn unwind_real_at<F: FloatBase>(
+        &self,
+        level: usize,
+        point: &WithFraction<MixedPoint<Self::U, N, Self::Cycles>, F>,
+    ) -> Real<F>
+    where
+        Self::Cycles: Into<Integer<Self::L>>,
+    {
+        let stride = F::from(self.stride(level).out()).expect("stride does not fit in the float type");
+        let whole: Integer<Self::L> = self.unwind(&point.whole).into();
+        let whole = F::from(whole.out()).expect("total does not fit in the float type");
+        Real::from(whole + point.fraction.out() * stride)
+    }
+
+    fn wind_real<F: FloatBase>(&self, total: Real<F>) -> WithFraction<BoundMixedPoint<'_, N, Self>, F>
+    where
+        Self::Cycles: From<Integer<Self::L>>,
+    {
+        self.wind_real_at(0, total)
+    }
+
+    fn unwind_real<F: FloatBase>(&self, point: &WithFraction<MixedPoint<Self::U, N, Self::Cycles>, F>) -> Real<F>
+    where
+        Self::Cycles: Into<Integer<Self::L>>,
+    {
+        self.unwind_real_at(0, point)
+    }
 }
@@ -0,0 +1,174 @@
+use numburs::{ConstrainedRep, ConstrainedSub, Integer, Natural, NumBase, Positive};
+
+use crate::{
+    cycle::types::{Cycle, CyclePoint},
+    mixed::types::{Mixed, MixedPoint},
+    trace::Trace,
+};
+
+// Shifts the zero point of a cycle, period or mixed system, so that a total
+// of 0 winds to where `inner` is at `offset`. For example, a week whose day 0
+// falls on the fourth weekday is a period of 7 with an offset of 3.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct Offset<T, O> {
+    pub inner: T,
+    pub offset: O,
+}
+
+impl<T, O> Offset<T, O> {
+    pub const fn new(inner: T, offset: O) -> Self {
+        Self { inner, offset }
+    }
+}
+
+// With natural totals, points before the offset are not reachable
+impl<N: NumBase, C: Cycle<N, Cycles = Natural<N>>> Cycle<N> for Offset<C, Natural<N>> {
+    type Cycles = Natural<N>;
+
+    fn size(&self) -> Positive<N> {
+        self.inner.size()
+    }
+
+    fn is_norm(&self, point: &CyclePoint<N, Self::Cycles>) -> bool {
+        self.inner.is_norm(point) && self.inner.unwind(point) >= self.offset
+    }
+
+    fn wind_inner(&self, cycles: &Self::Cycles) -> CyclePoint<N, Self::Cycles> {
+        self.inner.wind_inner(&(*cycles + self.offset))
+    }
+
+    fn unwind(&self, point: &CyclePoint<N, Self::Cycles>) -> Self::Cycles {
+        // Points below the offset are not norm, and no natural total unwinds to them
+        self.inner
+            .unwind(point)
+            .checked_sub(self.offset)
+            .expect("point is below the offset")
+    }
+
+    fn cycle_limit(&self) -> Option<Positive<N>> {
+        self.inner.cycle_limit()
+    }
+}
+
+impl<N: NumBase, L: NumBase, C: Cycle<N, Cycles = Integer<L>>> Cycle<N> for Offset<C, Integer<L>> {
+    type Cycles = Integer<L>;
+
+    fn size(&self) -> Positive<N> {
+        self.inner.size()
+    }
+
+    fn is_norm(&self, point: &CyclePoint<N, Self::Cycles>) -> bool {
+        self.inner.is_norm(point)
+    }
+
+    fn wind_inner(&self, cycles: &Self::Cycles) -> CyclePoint<N, Self::Cycles> {
+        self.inner.wind_inner(&(*cycles + self.offset))
+    }
+
+    fn unwind(&self, point: &CyclePoint<N, Self::Cycles>) -> Self::Cycles {
+        Integer::new(self.inner.unwind(point).out() - self.offset.out())
+    }
+
+    fn cycle_limit(&self) -> Option<Positive<N>> {
+        self.inner.cycle_limit()
+    }
+}
+
+// Traces are of `inner`, so their totals include the offset
+impl<const N: usize, L: NumBase, M: Mixed<N, Cycles = Integer<L>>> Mixed<N> for Offset<M, Integer<L>> {
+    type Cycles = Integer<L>;
+    type U = M::U;
+    type L = M::L;
+
+    fn is_norm(&self, point: &MixedPoint<Self::U, N, Self::Cycles>) -> bool {
+        self.inner.is_norm(point)
+    }
+
+    fn wind_inner(&self, total: &Self::Cycles) -> MixedPoint<Self::U, N, Self::Cycles> {
+        self.inner.wind_inner(&(*total + self.offset))
+    }
+
+    fn unwind(&self, point: &MixedPoint<Self::U, N, Self::Cycles>) -> Self::Cycles {
+        Integer::new(self.inner.unwind(point).out() - self.offset.out())
+    }
+
+    fn stride(&self, level: usize) -> Positive<Self::L> {
+        self.inner.stride(level)
+    }
+
+    fn unwind_trace(&self, point: &MixedPoint<Self::U, N, Self::Cycles>) -> Trace<Self::L, Self::Cycles> {
+        self.inner.unwind_trace(point)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use numburs::{int, integral::testing::*, nat, Integer};
+
+    use super::Offset;
+    use crate::{
+        cycle::{
+            limited_cycle::LimitedCycle,
+            period::{IdentityPeriodRepresentation, Period},
+            simple_cycle::SimpleCycle,
+            types::{Cycle, CyclePoint},
+        },
+        mixed::{
+            simple::SimpleMixed,
+            types::{Mixed, MixedPoint},
+        },
+    };
+
+    type Rep = IdentityPeriodRepresentation<i64>;
+    type MixedType = SimpleMixed<Rep, SimpleCycle<i64>, 2>;
+
+    #[test]
+    fn offset_cycle() {
+        let cycle = Offset::new(SimpleCycle::new(P7), N3);
+        assert_eq!(CyclePoint::new(N0, N3), cycle.wind(&N0).point);
+        assert_eq!(CyclePoint::new(N1, N0), cycle.wind(&N4).point);
+        for k in 0..30 {
+            assert_eq!(nat!(k), cycle.unwind(&cycle.wind(&nat!(k)).point));
+        }
+        assert!(cycle.point(N0, N2).is_none());
+        assert!(cycle.point(N0, N3).is_some());
+        assert_eq!(N0, cycle.unwind(&CyclePoint::new(N0, N3)));
+
+        let limited = Offset::new(LimitedCycle::new(P3, P2), N2);
+        assert_eq!(CyclePoint::new(N2, N3), limited.wind(&N7).point);
+        assert_eq!(Some(P2), limited.cycle_limit());
+    }
+
+    #[test]
+    #[should_panic(expected = "point is below the offset")]
+    fn offset_cycle_below() {
+        Offset::new(SimpleCycle::new(P7), N3).unwind(&CyclePoint::new(N0, N2));
+    }
+
+    #[test]
+    fn offset_period() {
+        let week = Offset::new(Period::<Rep>::new(P7), I3);
+        assert_eq!(CyclePoint::new(I0, N3), week.wind(&I0).point);
+        assert_eq!(CyclePoint::new(I1, N0), week.wind(&I4).point);
+        assert_eq!(CyclePoint::new(-I1, N6), week.wind(&-I4).point);
+        assert_eq!(CyclePoint::new(-I1, N0), week.wind(&int!(-10)).point);
+        assert_eq!(CyclePoint::new(int!(-2), N6), week.wind(&int!(-11)).point);
+        for k in -30..30 {
+            assert_eq!(int!(k), week.unwind(&week.wind(&int!(k)).point));
+        }
+    }
+
+    #[test]
+    fn offset_mixed() {
+        const MIXED: MixedType = MixedType::from_sizes([2, 3]);
+        let shifted = Offset::new(MIXED, int!(5));
+        assert_eq!(MixedPoint::new(I0, [N1, N2]), shifted.wind(I0).point);
+        assert_eq!(MixedPoint::new(I1, [N0, N0]), shifted.wind(I1).point);
+        assert_eq!(MixedPoint::new(I0, [N0, N0]), shifted.wind(-I5).point);
+        assert_eq!(MixedPoint::new(-I1, [N1, N2]), shifted.wind(int!(-6)).point);
+        for k in -30..30 {
+            assert_eq!(int!(k), shifted.unwind(&shifted.wind(int!(k)).point));
+        }
+        assert_eq!(MIXED.wind_trace(&I7), shifted.wind_trace(&I2));
+    }
+}
@@ -0,0 +1,256 @@
+use std::sync::Arc;
+
+use derive_more::Constructor;
+use numburs::NumBase;
+
+use crate::{
+    cycle::types::{BoundCyclePoint, Cycle, CyclePoint},
+    filter::types::{BoundFilterPoint, Filter, FilterPoint},
+    mixed::types::{BoundMixedPhase, BoundMixedPoint, Mixed, MixedPoint},
+    types::Phase,
+};
+
+// Bound points that share their schema instead of borrowing it, so they can
+// outlive it and be built from schemas made at runtime. Converting from a
+// borrowed point clones the schema once.
+
+#[derive(Debug, PartialEq, Eq, Constructor)]
+pub struct OwnedCyclePoint<N: NumBase, C: Cycle<N>> {
+    pub scheme: Arc<C>,
+    pub point: CyclePoint<N, C::Cycles>,
+}
+
+#[derive(Debug, PartialEq, Eq, Constructor)]
+pub struct OwnedMixedPhase<const N: usize, P: Mixed<N>> {
+    pub schema: Arc<P>,
+    pub phase: Phase<P::U, N>,
+}
+
+#[derive(Debug, PartialEq, Eq, Constructor)]
+pub struct OwnedMixedPoint<const N: usize, P: Mixed<N>> {
+    pub schema: Arc<P>,
+    pub point: MixedPoint<P::U, N, P::Cycles>,
+}
+
+#[derive(Debug, PartialEq, Eq, Constructor)]
+pub struct OwnedFilterPoint<const N: usize, P: Filter<N>> {
+    pub schema: Arc<P>,
+    pub point: FilterPoint<P::U, N, P::Cycle>,
+}
+
+impl<N: NumBase, C: Cycle<N>> OwnedCyclePoint<N, C> {
+    pub fn bind(scheme: Arc<C>, point: CyclePoint<N, C::Cycles>) -> Option<Self> {
+        Some(point).filter(|p| scheme.is_norm(p)).map(|p| Self::new(scheme, p))
+    }
+
+    pub fn wind(scheme: Arc<C>, cycles: &C::Cycles) -> Self {
+        let point = scheme.wind_inner(cycles);
+        Self::new(scheme, point)
+    }
+
+    pub fn unwind(&self) -> C::Cycles {
+        self.scheme.unwind(&self.point)
+    }
+
+    pub fn as_bound(&self) -> BoundCyclePoint<'_, N, C>
+    where
+        C::Cycles: Clone,
+    {
+        BoundCyclePoint::new(&self.scheme, self.point.clone())
+    }
+}
+
+impl<const N: usize, P: Mixed<N>> OwnedMixedPhase<N, P> {
+    pub fn as_bound(&self) -> BoundMixedPhase<'_, N, P> {
+        BoundMixedPhase::new(&self.schema, self.phase)
+    }
+}
+
+impl<const N: usize, P: Mixed<N>> OwnedMixedPoint<N, P> {
+    pub fn bind(schema: Arc<P>, point: MixedPoint<P::U, N, P::Cycles>) -> Option<Self> {
+        Some(point).filter(|p| schema.is_norm(p)).map(|p| Self::new(schema, p))
+    }
+
+    pub fn wind(schema: Arc<P>, total: P::Cycles) -> Self {
+        let point = schema.wind_inner(&total);
+        Self::new(schema, point)
+    }
+
+    pub fn unwind(&self) -> P::Cycles {
+        self.schema.unwind(&self.point)
+    }
+
+    pub fn phase(&self) -> OwnedMixedPhase<N, P> {
+        OwnedMixedPhase::new(self.schema.clone(), self.point.phase)
+    }
+
+    pub fn as_bound(&self) -> BoundMixedPoint<'_, N, P>
+    where
+        P::Cycles: Clone,
+    {
+        BoundMixedPoint::new(&self.schema, self.point.clone())
+    }
+}
+
+impl<const N: usize, P: Filter<N>> OwnedFilterPoint<N, P> {
+    pub fn bind(schema: Arc<P>, point: FilterPoint<P::U, N, P::Cycle>) -> Option<Self> {
+        Some(point).filter(|p| schema.is_norm(p)).map(|p| Self::new(schema, p))
+    }
+
+    pub fn wind(schema: Arc<P>, total: P::Cycle) -> Self {
+        let point = schema.wind_inner(&total);
+        Self::new(schema, point)
+    }
+
+    pub fn unwind(&self) -> P::Cycle {
+        self.schema.unwind(&self.point)
+    }
+
+    pub fn as_bound(&self) -> BoundFilterPoint<'_, N, P>
+    where
+        P::Cycle: Clone,
+    {
+        BoundFilterPoint::new(&self.schema, self.point.clone())
+    }
+}
+
+// Cloning shares the schema rather than copying it
+impl<N: NumBase, C: Cycle<N>> Clone for OwnedCyclePoint<N, C>
+where
+    C::Cycles: Clone,
+{
+    fn clone(&self) -> Self {
+        Self::new(self.scheme.clone(), self.point.clone())
+    }
+}
+
+impl<const N: usize, P: Mixed<N>> Clone for OwnedMixedPhase<N, P> {
+    fn clone(&self) -> Self {
+        Self::new(self.schema.clone(), self.phase)
+    }
+}
+
+impl<const N: usize, P: Mixed<N>> Clone for OwnedMixedPoint<N, P>
+where
+    P::Cycles: Clone,
+{
+    fn clone(&self) -> Self {
+        Self::new(self.schema.clone(), self.point.clone())
+    }
+}
+
+impl<const N: usize, P: Filter<N>> Clone for OwnedFilterPoint<N, P>
+where
+    P::Cycle: Clone,
+{
+    fn clone(&self) -> Self {
+        Self::new(self.schema.clone(), self.point.clone())
+    }
+}
+
+impl<N: NumBase, C: Cycle<N> + Clone> From<BoundCyclePoint<'_, N, C>> for OwnedCyclePoint<N, C> {
+    fn from(bound: BoundCyclePoint<'_, N, C>) -> Self {
+        Self::new(Arc::new(bound.scheme.clone()), bound.point)
+    }
+}
+
+impl<const N: usize, P: Mixed<N> + Clone> From<BoundMixedPhase<'_, N, P>> for OwnedMixedPhase<N, P> {
+    fn from(bound: BoundMixedPhase<'_, N, P>) -> Self {
+        Self::new(Arc::new(bound.schema.clone()), bound.phase)
+    }
+}
+
+impl<const N: usize, P: Mixed<N> + Clone> From<BoundMixedPoint<'_, N, P>> for OwnedMixedPoint<N, P> {
+    fn from(bound: BoundMixedPoint<'_, N, P>) -> Self {
+        Self::new(Arc::new(bound.schema.clone()), bound.point)
+    }
+}
+
+impl<const N: usize, P: Filter<N> + Clone> From<BoundFilterPoint<'_, N, P>> for OwnedFilterPoint<N, P> {
+    fn from(bound: BoundFilterPoint<'_, N, P>) -> Self {
+        Self::new(Arc::new(bound.schema.clone()), bound.point)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use numburs::{int, integral::testing::*, pos, Integer, LowerBoundedRep, Positive};
+
+    use super::{OwnedCyclePoint, OwnedFilterPoint, OwnedMixedPoint};
+    use crate::{
+        cycle::{
+            limited_cycle::LimitedCycle,
+            period::{IdentityPeriodRepresentation, Period},
+            simple_cycle::SimpleCycle,
+            types::{Cycle, CyclePoint},
+        },
+        filter::{simple::SimpleFilter, types::Filter},
+        mixed::{
+            simple::SimpleMixed,
+            types::{Mixed, MixedPoint},
+        },
+    };
+
+    type Rep = IdentityPeriodRepresentation<i64>;
+    type MixedType = SimpleMixed<Rep, SimpleCycle<i64>, 3>;
+    type FilterType = SimpleFilter<Rep, LimitedCycle<i64>, 2>;
+
+    // Outlives the schema it was wound from
+    struct Stored {
+        point: OwnedMixedPoint<3, MixedType>,
+    }
+
+    fn store(sizes: [i64; 3], total: i64) -> Stored {
+        let mixed = Arc::new(MixedType::from_simple_factors(sizes.map(Positive::at_least)));
+        Stored {
+            point: OwnedMixedPoint::wind(mixed, int!(total)),
+        }
+    }
+
+    #[test]
+    fn owned_mixed_point() {
+        let stored = store([2, 3, 4], 29);
+        let mixed = MixedType::from_sizes([2, 3, 4]);
+        assert_eq!(mixed.wind(int!(29)).point, stored.point.point);
+        assert_eq!(int!(29), stored.point.unwind());
+        assert_eq!(mixed.wind(int!(29)), stored.point.as_bound());
+        assert_eq!(mixed.wind(int!(29)).phase(), stored.point.phase().as_bound());
+        assert_eq!(stored.point, mixed.wind(int!(29)).into());
+
+        let copy = stored.point.clone();
+        assert!(Arc::ptr_eq(&copy.schema, &stored.point.schema));
+
+        let schema = stored.point.schema.clone();
+        assert!(OwnedMixedPoint::bind(schema.clone(), MixedPoint::new(I1, [N1, N2, N3])).is_some());
+        assert!(OwnedMixedPoint::bind(schema, MixedPoint::new(I1, [N2, N2, N3])).is_none());
+    }
+
+    #[test]
+    fn owned_cycle_point() {
+        let period = Arc::new(Period::<Rep>::new(P7));
+        let point = OwnedCyclePoint::wind(period.clone(), &int!(-3));
+        assert_eq!(CyclePoint::new(-I1, N4), point.point);
+        assert_eq!(int!(-3), point.unwind());
+        assert_eq!(period.wind(&int!(-3)), point.as_bound());
+        assert_eq!(point, OwnedCyclePoint::from(period.wind(&int!(-3))));
+        assert!(OwnedCyclePoint::bind(period, CyclePoint::new(I0, N7)).is_none());
+    }
+
+    #[test]
+    fn owned_filter_point() {
+        let filter = FilterType::new(
+            Period::new(pos!(50)),
+            [
+                LimitedCycle::new(pos!(10), pos!(3)),
+                LimitedCycle::new(pos!(3), pos!(2)),
+            ],
+        );
+        let point = OwnedFilterPoint::wind(Arc::new(filter.clone()), int!(123));
+        assert_eq!(filter.wind(int!(123)).point, point.point);
+        assert_eq!(int!(123), point.unwind());
+        assert_eq!(point, OwnedFilterPoint::from(filter.wind(int!(123))));
+        assert_eq!(Integer::new(123), filter.unwind(&point.as_bound().point));
+    }
+}
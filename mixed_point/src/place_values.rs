@@ -0,0 +1,524 @@
+use core::marker::PhantomData;
+
+use num_traits::{CheckedMul, ConstOne};
+use numburs::{ConstrainedRep, HasZero, Integer, Natural, NumBase};
+
+use crate::{
+    cycle::{
+        period::{IdentityPeriodRepresentation, Period, PeriodRepresentation, WideningPeriodRepresentation},
+        types::Cycle,
+    },
+    filter::types::FilterPoint,
+    mixed::types::MixedPoint,
+};
+
+// The sizes, limits and place values of a system, in the representation's
+// cycle type, which `SimpleMixed` computes once when built and winds through.
+// When the period and every factor size are powers of two and nothing is
+// limited, digits are read with shifts and masks instead of divisions.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct PlaceValues<R: PeriodRepresentation, const N: usize> {
+    period: R::Cycles,
+    sizes: [R::Cycles; N],
+    limits: [Option<R::Cycles>; N],
+    strides: [R::Cycles; N],
+    shifts: Option<Shifts<N>>,
+    representation: PhantomData<R>,
+}
+
+// Where each digit starts and the width of the period, in bits
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+struct Shifts<const N: usize> {
+    digits: [usize; N],
+    period: usize,
+}
+
+// The filter counterpart, with a shift for each power of two size
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct FilterPlaceValues<R: PeriodRepresentation, const N: usize> {
+    period: R::Cycles,
+    period_shift: Option<usize>,
+    sizes: [R::Cycles; N],
+    limits: [Option<R::Cycles>; N],
+    shifts: [Option<usize>; N],
+    representation: PhantomData<R>,
+}
+
+fn log2<U: NumBase>(x: U) -> Option<usize> {
+    Some(x.trailing_zeros() as usize).filter(|_| x.count_ones() == 1)
+}
+
+fn project<R: PeriodRepresentation>(digit: R::Cycles) -> Natural<R::Phase> {
+    R::project(Natural::new_unchecked(digit))
+}
+
+// Splits `total` by a period into a floored cycle and a phase in [0, period)
+fn euclid<U: NumBase>(total: U, period: U) -> (U, U) {
+    let (cycle, phase) = (total / period, total % period);
+    if phase < U::ZERO {
+        (cycle - U::ONE, phase + period)
+    } else {
+        (cycle, phase)
+    }
+}
+
+impl<R: PeriodRepresentation, const N: usize> PlaceValues<R, N> {
+    pub(crate) fn new<C: Cycle<R::Cycles, Cycles = Natural<R::Cycles>>>(period: &Period<R>, factors: &[C; N]) -> Self {
+        let period = period.size().out();
+        let sizes = factors.each_ref().map(|c| c.size().out());
+        let limits = factors.each_ref().map(|c| c.cycle_limit().map(|limit| limit.out()));
+        let mut strides = [R::Cycles::ONE; N];
+        for k in 1..N {
+            strides[k] = strides[k - 1]
+                .checked_mul(&sizes[k - 1])
+                .expect("place value of a factor overflows the cycle type");
+        }
+
+        let unlimited = limits.iter().all(Option::is_none);
+        let shifts = log2(period)
+            .filter(|_| unlimited && sizes.iter().all(|size| log2(*size).is_some()))
+            .map(|period| Shifts {
+                digits: strides.map(|stride| log2(stride).unwrap_or(0)),
+                period,
+            });
+        PlaceValues {
+            period,
+            sizes,
+            limits,
+            strides,
+            shifts,
+            representation: PhantomData,
+        }
+    }
+}
+
+impl<R: PeriodRepresentation, const N: usize> PlaceValues<R, N> {
+    pub fn is_shifted(&self) -> bool {
+        self.shifts.is_some()
+    }
+
+    pub fn strides(&self) -> &[R::Cycles; N] {
+        &self.strides
+    }
+
+    // Matches `Mixed::wind_inner` on the system these were computed from
+    pub fn wind_inner(&self, total: &Integer<R::Cycles>) -> MixedPoint<R::Phase, N, Integer<R::Cycles>> {
+        let total = total.out();
+        let mut phases = [Natural::ZERO; N];
+        if let Some(Shifts { digits, period }) = self.shifts {
+            let phase = total & (self.period - R::Cycles::ONE);
+            for k in 0..N {
+                phases[k] = project::<R>((phase >> digits[k]) & (self.sizes[k] - R::Cycles::ONE));
+            }
+            return MixedPoint::new(Integer::new(total >> period), phases);
+        }
+
+        let (cycle, mut phase) = euclid(total, self.period);
+        for (k, digit) in phases.iter_mut().enumerate() {
+            let mut q = phase / self.sizes[k];
+            if let Some(limit) = self.limits[k] {
+                q = q.min(limit);
+            }
+            *digit = project::<R>(phase - q * self.sizes[k]);
+            phase = q;
+        }
+        MixedPoint::new(Integer::new(cycle), phases)
+    }
+
+    // Matches `Mixed::unwind`, and likewise does not check for overflow
+    pub fn unwind(&self, point: &MixedPoint<R::Phase, N, Integer<R::Cycles>>) -> Integer<R::Cycles> {
+        let MixedPoint { cycle, phase } = point;
+        if let Some(Shifts { digits, period }) = self.shifts {
+            let total = (0..N).fold(cycle.out() << period, |t, k| {
+                t + (R::embed(phase[k]).out() << digits[k])
+            });
+            return Integer::new(total);
+        }
+        let total = (0..N).fold(cycle.out() * self.period, |t, k| {
+            t + R::embed(phase[k]).out() * self.strides[k]
+        });
+        Integer::new(total)
+    }
+
+    // Winds `totals` into the points at the same positions
+    pub fn wind_into(&self, totals: &[Integer<R::Cycles>], points: &mut [MixedPoint<R::Phase, N, Integer<R::Cycles>>]) {
+        assert_eq!(totals.len(), points.len(), "expected a point for each total");
+        for (total, point) in totals.iter().zip(points.iter_mut()) {
+            *point = self.wind_inner(total);
+        }
+    }
+
+    pub fn unwind_into(
+        &self,
+        points: &[MixedPoint<R::Phase, N, Integer<R::Cycles>>],
+        totals: &mut [Integer<R::Cycles>],
+    ) {
+        assert_eq!(points.len(), totals.len(), "expected a total for each point");
+        for (point, total) in points.iter().zip(totals.iter_mut()) {
+            *total = self.unwind(point);
+        }
+    }
+}
+
+impl<R: PeriodRepresentation, const N: usize> FilterPlaceValues<R, N> {
+    pub(crate) fn new<C: Cycle<R::Cycles, Cycles = Natural<R::Cycles>>>(period: &Period<R>, factors: &[C; N]) -> Self {
+        let period = period.size().out();
+        let sizes = factors.each_ref().map(|c| c.size().out());
+        FilterPlaceValues {
+            period,
+            period_shift: log2(period),
+            sizes,
+            limits: factors.each_ref().map(|c| c.cycle_limit().map(|limit| limit.out())),
+            shifts: sizes.map(log2),
+            representation: PhantomData,
+        }
+    }
+}
+
+// The same computations in const fns, for the systems that are constants
+macro_rules! const_place_values {
+    ($($t:ty),+) => {
+        $(
+            const_place_values!(@rep IdentityPeriodRepresentation<$t>, $t);
+        )+
+    };
+    (widening: $($p:ty => $c:ty),+) => {
+        $(
+            const_place_values!(@rep WideningPeriodRepresentation<$p, $c>, $c);
+        )+
+    };
+    (@rep $rep:ty, $t:ty) => {
+        impl<const N: usize> PlaceValues<$rep, N> {
+            pub(crate) const fn from_parts(period: $t, sizes: [$t; N], limits: [Option<$t>; N]) -> Self {
+                let mut strides: [$t; N] = [1; N];
+                let mut unlimited = true;
+                let mut powers = period.count_ones() == 1;
+                let mut k = 0;
+                while k < N {
+                    if k > 0 {
+                        strides[k] = match strides[k - 1].checked_mul(sizes[k - 1]) {
+                            Some(stride) => stride,
+                            None => panic!("place value of a factor overflows the cycle type"),
+                        };
+                    }
+                    unlimited &= limits[k].is_none();
+                    powers &= sizes[k].count_ones() == 1;
+                    k += 1;
+                }
+
+                let shifts = if unlimited && powers {
+                    let mut digits = [0; N];
+                    let mut k = 0;
+                    while k < N {
+                        digits[k] = strides[k].trailing_zeros() as usize;
+                        k += 1;
+                    }
+                    Some(Shifts {
+                        digits,
+                        period: period.trailing_zeros() as usize,
+                    })
+                } else {
+                    None
+                };
+                PlaceValues {
+                    period,
+                    sizes,
+                    limits,
+                    strides,
+                    shifts,
+                    representation: PhantomData,
+                }
+            }
+        }
+
+        impl<const N: usize> FilterPlaceValues<$rep, N> {
+            pub(crate) const fn from_parts(period: $t, sizes: [$t; N], limits: [Option<$t>; N]) -> Self {
+                let mut shifts = [None; N];
+                let mut k = 0;
+                while k < N {
+                    if sizes[k].count_ones() == 1 {
+                        shifts[k] = Some(sizes[k].trailing_zeros() as usize);
+                    }
+                    k += 1;
+                }
+                FilterPlaceValues {
+                    period,
+                    period_shift: if period.count_ones() == 1 {
+                        Some(period.trailing_zeros() as usize)
+                    } else {
+                        None
+                    },
+                    sizes,
+                    limits,
+                    shifts,
+                    representation: PhantomData,
+                }
+            }
+        }
+    };
+}
+
+const_place_values!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+const_place_values!(widening: u8 => i64, u16 => i64, u32 => i64, u8 => i128, u16 => i128, u32 => i128);
+
+impl<R: PeriodRepresentation, const N: usize> FilterPlaceValues<R, N> {
+    // Matches `Filter::wind_inner` on the filter these were computed from
+    pub fn wind_inner(&self, total: &Integer<R::Cycles>) -> FilterPoint<R::Phase, N, Integer<R::Cycles>> {
+        let total = total.out();
+        let (cycle, mut phase) = match self.period_shift {
+            Some(shift) => (total >> shift, total & (self.period - R::Cycles::ONE)),
+            None => euclid(total, self.period),
+        };
+        let mut phases = [Natural::ZERO; N];
+        for k in 0..N {
+            let mut q = match self.shifts[k] {
+                Some(shift) => phase >> shift,
+                None => phase / self.sizes[k],
+            };
+            if let Some(limit) = self.limits[k] {
+                q = q.min(limit);
+            }
+            phases[N - k - 1] = project::<R>(q);
+            phase = phase - q * self.sizes[k];
+        }
+        FilterPoint::new(MixedPoint::new(Integer::new(cycle), phases), project::<R>(phase))
+    }
+
+    // Matches `Filter::unwind`, and likewise does not check for overflow
+    pub fn unwind(&self, point: &FilterPoint<R::Phase, N, Integer<R::Cycles>>) -> Integer<R::Cycles> {
+        let FilterPoint { point, remainder } = point;
+        let mut phase = R::embed(*remainder).out();
+        for k in (0..N).rev() {
+            phase = R::embed(point.phase[N - k - 1]).out() * self.sizes[k] + phase;
+        }
+        Integer::new(point.cycle.out() * self.period + phase)
+    }
+
+    pub fn wind_into(
+        &self,
+        totals: &[Integer<R::Cycles>],
+        points: &mut [FilterPoint<R::Phase, N, Integer<R::Cycles>>],
+    ) {
+        assert_eq!(totals.len(), points.len(), "expected a point for each total");
+        for (total, point) in totals.iter().zip(points.iter_mut()) {
+            *point = self.wind_inner(total);
+        }
+    }
+
+    pub fn unwind_into(
+        &self,
+        points: &[FilterPoint<R::Phase, N, Integer<R::Cycles>>],
+        totals: &mut [Integer<R::Cycles>],
+    ) {
+        assert_eq!(points.len(), totals.len(), "expected a total for each point");
+        for (point, total) in points.iter().zip(totals.iter_mut()) {
+            *total = self.unwind(point);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use numburs::{int, integral::testing::*, pos, Integer, Natural};
+
+    use crate::{
+        cycle::{
+            cycle_kind::CycleKind,
+            limited_cycle::LimitedCycle,
+            period::{IdentityPeriodRepresentation, Period},
+            simple_cycle::SimpleCycle,
+            types::{Cycle, CyclePoint},
+        },
+        filter::{
+            simple::SimpleFilter,
+            types::{Filter, FilterPoint},
+        },
+        mixed::{
+            simple::SimpleMixed,
+            types::{Mixed, MixedPoint},
+        },
+    };
+
+    type Rep = IdentityPeriodRepresentation<i64>;
+    type MixedType<const N: usize> = SimpleMixed<Rep, SimpleCycle<i64>, N>;
+
+    fn totals() -> Vec<i64> {
+        (-700..700).collect()
+    }
+
+    // Winds through the period and then each cycle, which the place values
+    // must agree with
+    fn through_cycles<C: Cycle<i64, Cycles = Natural<i64>>, const N: usize>(
+        mixed: &SimpleMixed<Rep, C, N>,
+        total: i64,
+    ) -> MixedPoint<i64, N, Integer<i64>> {
+        let CyclePoint { cycle, mut phase } = mixed.period().wind(&int!(total)).point;
+        let mut phases = [N0; N];
+        for (k, factor) in mixed.factors().iter().enumerate() {
+            let point = factor.wind(&phase).point;
+            phases[k] = point.phase;
+            phase = point.cycle;
+        }
+        MixedPoint::new(cycle, phases)
+    }
+
+    fn filter_through_cycles<C: Cycle<i64, Cycles = Natural<i64>>, const N: usize>(
+        filter: &SimpleFilter<Rep, C, N>,
+        total: i64,
+    ) -> FilterPoint<i64, N, Integer<i64>> {
+        let CyclePoint { cycle, mut phase } = filter.period().wind(&int!(total)).point;
+        let mut phases = [N0; N];
+        for (k, factor) in filter.factors().iter().enumerate() {
+            let point = factor.wind(&phase).point;
+            phases[N - k - 1] = point.cycle;
+            phase = point.phase;
+        }
+        FilterPoint::new(MixedPoint::new(cycle, phases), phase)
+    }
+
+    // Unwinding by multiplication overflows on the way to the extremes, as
+    // `Mixed::unwind` does, so only shifted systems are checked there
+    fn extreme_totals() -> Vec<i64> {
+        let mut totals = totals();
+        totals.extend([i64::MIN, i64::MIN + 1, i64::MAX - 1, i64::MAX]);
+        totals
+    }
+
+    #[test]
+    fn shifted_mixed() {
+        let clock = MixedType::<5>::from_sizes([2, 64, 64, 8, 16]);
+        let places = clock.place_values();
+        assert!(places.is_shifted());
+        assert_eq!(&[1, 2, 128, 8192, 65536], places.strides());
+        for total in extreme_totals() {
+            let point = places.wind_inner(&int!(total));
+            assert_eq!(through_cycles(&clock, total), point);
+            assert_eq!(int!(total), places.unwind(&point));
+        }
+    }
+
+    #[test]
+    fn divided_mixed() {
+        let mixed = MixedType::<3>::from_sizes([3, 4, 5]);
+        let places = mixed.place_values();
+        assert!(!places.is_shifted());
+        for total in totals() {
+            let point = places.wind_inner(&int!(total));
+            assert_eq!(through_cycles(&mixed, total), point);
+            assert_eq!(int!(total), places.unwind(&point));
+        }
+
+        // A period past the factors still shifts, while a limit does not
+        let mismatched = MixedType::new(Period::new(pos!(16)), [SimpleCycle::new(P2), SimpleCycle::new(P4)]);
+        let places = mismatched.place_values();
+        assert!(places.is_shifted());
+        for total in totals() {
+            assert_eq!(through_cycles(&mismatched, total), places.wind_inner(&int!(total)));
+        }
+        let limited = SimpleMixed::<Rep, _, 2>::from_factors([LimitedCycle::new(P2, P2), LimitedCycle::new(P4, P2)]);
+        let places = limited.place_values();
+        assert!(!places.is_shifted());
+        for total in totals() {
+            assert_eq!(through_cycles(&limited, total), places.wind_inner(&int!(total)));
+        }
+    }
+
+    #[test]
+    fn filters() {
+        let filter = SimpleFilter::<Rep, _, 3>::new(
+            Period::new(pos!(93502)),
+            [
+                CycleKind::simple(pos!(5844)),
+                CycleKind::simple(pos!(2922)),
+                CycleKind::limited(pos!(365), pos!(7)),
+            ],
+        );
+        let shifted = SimpleFilter::<Rep, _, 2>::new(
+            Period::new(pos!(64)),
+            [
+                LimitedCycle::new(pos!(16), pos!(3)),
+                LimitedCycle::new(pos!(4), pos!(5)),
+            ],
+        );
+        let (places, shifted_places) = (filter.place_values(), shifted.place_values());
+        let mut totals = totals();
+        totals.extend((0..100).map(|k| k * 93502 - 1));
+        for total in totals {
+            let point = places.wind_inner(&int!(total));
+            assert_eq!(filter_through_cycles(&filter, total), point);
+            assert_eq!(int!(total), places.unwind(&point));
+        }
+        for total in extreme_totals() {
+            let point = shifted_places.wind_inner(&int!(total));
+            assert_eq!(filter_through_cycles(&shifted, total), point);
+            assert_eq!(int!(total), shifted_places.unwind(&point));
+        }
+    }
+
+    // Equality compares the place values too
+    #[test]
+    fn const_constructors() {
+        let sizes = [pos!(2), pos!(64), pos!(64), pos!(8), pos!(16)];
+        assert_eq!(
+            MixedType::<5>::from_simple_factors(sizes),
+            MixedType::<5>::from_sizes([2, 64, 64, 8, 16])
+        );
+        assert_eq!(
+            MixedType::<3>::from_simple_factors([P3, P4, P5]),
+            MixedType::<3>::from_sizes([3, 4, 5])
+        );
+        let factors = [CycleKind::simple(pos!(5844)), CycleKind::limited(pos!(365), pos!(7))];
+        assert_eq!(
+            SimpleFilter::<Rep, _, 2>::new(Period::new(pos!(93502)), factors),
+            SimpleFilter::<Rep, _, 2>::from_kinds(Period::new(pos!(93502)), factors)
+        );
+        let factors = [CycleKind::simple(pos!(16)), CycleKind::limited(pos!(4), pos!(5))];
+        assert_eq!(
+            SimpleFilter::<Rep, _, 2>::new(Period::new(pos!(64)), factors),
+            SimpleFilter::<Rep, _, 2>::from_kinds(Period::new(pos!(64)), factors)
+        );
+    }
+
+    #[test]
+    fn batches() {
+        let clock = MixedType::<5>::from_sizes([2, 64, 64, 8, 16]);
+        let places = clock.place_values();
+        let extremes: Vec<Integer<i64>> = extreme_totals().into_iter().map(Integer::new).collect();
+        let mut points = vec![MixedPoint::new(I0, [N0; 5]); extremes.len()];
+        places.wind_into(&extremes, &mut points);
+        let mut unwound = vec![I0; extremes.len()];
+        places.unwind_into(&points, &mut unwound);
+        assert_eq!(extremes, unwound);
+        assert_eq!(clock.wind_inner(&extremes[3]), points[3]);
+
+        let totals: Vec<Integer<i64>> = totals().into_iter().map(Integer::new).collect();
+        let mut unwound = vec![I0; totals.len()];
+        let filter = SimpleFilter::<Rep, _, 1>::new(Period::new(pos!(10)), [LimitedCycle::new(pos!(3), pos!(2))]);
+        let places = filter.place_values();
+        let mut points = vec![filter.wind_inner(&I0); totals.len()];
+        places.wind_into(&totals, &mut points);
+        places.unwind_into(&points, &mut unwound);
+        assert_eq!(totals, unwound);
+    }
+
+    #[test]
+    #[should_panic(expected = "place value of a factor overflows the cycle type")]
+    fn stride_overflow() {
+        SimpleMixed::<IdentityPeriodRepresentation<i8>, _, 3>::new(
+            Period::new(pos!(8i8)),
+            [
+                SimpleCycle::new(pos!(16i8)),
+                SimpleCycle::new(pos!(16i8)),
+                SimpleCycle::new(pos!(2i8)),
+            ],
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn batch_length_mismatch() {
+        let mixed = MixedType::<2>::from_sizes([2, 3]);
+        let places = mixed.place_values();
+        places.wind_into(&[I0, I1], &mut [MixedPoint::new(I0, [N0, N0])]);
+    }
+}
@@ -0,0 +1,696 @@
+use core::{
+    fmt::{self, Display},
+    marker::PhantomData,
+};
+
+use numburs::{ConstrainedRep, HasZero, Integer, Natural, NumBase, Positive};
+use serde::{
+    de::{DeserializeSeed, Error, MapAccess, Visitor},
+    ser::{SerializeMap, SerializeSeq, SerializeStruct},
+    Deserialize, Deserializer, Serialize, Serializer,
+};
+
+use crate::{
+    cycle::{
+        cycle_kind::CycleKind,
+        limited_cycle::LimitedCycle,
+        period::{Period, PeriodRepresentation},
+        simple_cycle::SimpleCycle,
+        types::Cycle,
+    },
+    filter::simple::SimpleFilter,
+    mixed::{
+        simple::SimpleMixed,
+        types::{BoundMixedPoint, Mixed, MixedPointLegend, OwnedMixedPointLegend},
+    },
+    types::{OwnedPhaseLegend, Phase, PhaseLegend},
+    validation::Violation,
+};
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum DefinitionError {
+    NotPositive(&'static str),
+    WrongLength { expected: usize, found: usize },
+    PeriodMismatch,
+    Oversized { index: usize },
+    UnreachableLimit { index: usize },
+    TruncatedLast { index: usize },
+    PeriodOverflow,
+    DigitOverflow { index: usize },
+}
+
+impl Display for DefinitionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DefinitionError::NotPositive(field) => write!(f, "'{}' must be positive", field),
+            DefinitionError::WrongLength { expected, found } => {
+                write!(f, "expected {} entries, found {}", expected, found)
+            }
+            DefinitionError::PeriodMismatch => write!(f, "period does not match the product of factor sizes"),
+            DefinitionError::Oversized { index } => write!(f, "factor {} does not fit in the cycle above it", index),
+            DefinitionError::UnreachableLimit { index } => write!(f, "factor {} never reaches its limit", index),
+            DefinitionError::TruncatedLast { index } => {
+                write!(f, "factor {} ends the period on a truncated last cycle", index)
+            }
+            DefinitionError::PeriodOverflow => write!(f, "the product of factor sizes overflows"),
+            DefinitionError::DigitOverflow { index } => write!(f, "digit {} does not fit in the phase type", index),
+        }
+    }
+}
+
+impl std::error::Error for DefinitionError {}
+
+fn positive<N: NumBase>(x: N, field: &'static str) -> Result<Positive<N>, DefinitionError> {
+    Positive::rep(x).ok_or(DefinitionError::NotPositive(field))
+}
+
+fn sized<T, const N: usize>(items: Vec<T>) -> Result<[T; N], DefinitionError> {
+    let found = items.len();
+    items
+        .try_into()
+        .map_err(|_| DefinitionError::WrongLength { expected: N, found })
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Cycles
+////////////////////////////////////////////////////////////////////////////////
+
+#[derive(Deserialize)]
+struct SimpleCycleData<N> {
+    size: N,
+}
+
+#[derive(Deserialize)]
+struct LimitedCycleData<N> {
+    size: N,
+    limit: N,
+}
+
+#[derive(Deserialize)]
+struct CycleKindData<N> {
+    size: N,
+    limit: Option<N>,
+}
+
+#[derive(Deserialize)]
+struct PeriodData<N> {
+    size: N,
+}
+
+impl<N: NumBase + Serialize> Serialize for SimpleCycle<N> {
+    fn serialize<S: Serializer>(&self, s: S) -> Result<S::Ok, S::Error> {
+        let mut state = s.serialize_struct("SimpleCycle", 1)?;
+        state.serialize_field("size", &self.size().out())?;
+        state.end()
+    }
+}
+
+impl<'de, N: NumBase + Deserialize<'de>> Deserialize<'de> for SimpleCycle<N> {
+    fn deserialize<D: Deserializer<'de>>(d: D) -> Result<Self, D::Error> {
+        let SimpleCycleData { size } = SimpleCycleData::deserialize(d)?;
+        Ok(Self::new(positive(size, "size").map_err(D::Error::custom)?))
+    }
+}
+
+impl<N: NumBase + Serialize> Serialize for LimitedCycle<N> {
+    fn serialize<S: Serializer>(&self, s: S) -> Result<S::Ok, S::Error> {
+        let mut state = s.serialize_struct("LimitedCycle", 2)?;
+        state.serialize_field("size", &self.size().out())?;
+        state.serialize_field("limit", &self.limit().out())?;
+        state.end()
+    }
+}
+
+impl<'de, N: NumBase + Deserialize<'de>> Deserialize<'de> for LimitedCycle<N> {
+    fn deserialize<D: Deserializer<'de>>(d: D) -> Result<Self, D::Error> {
+        let LimitedCycleData { size, limit } = LimitedCycleData::deserialize(d)?;
+        let size = positive(size, "size").map_err(D::Error::custom)?;
+        let limit = positive(limit, "limit").map_err(D::Error::custom)?;
+        Ok(Self::new(size, limit))
+    }
+}
+
+// A cycle kind is written as the cycle it holds, with a limit marking it as limited
+impl<N: NumBase + Serialize> Serialize for CycleKind<N> {
+    fn serialize<S: Serializer>(&self, s: S) -> Result<S::Ok, S::Error> {
+        match self {
+            CycleKind::Simple(cycle) => cycle.serialize(s),
+            CycleKind::Limited(cycle) => cycle.serialize(s),
+        }
+    }
+}
+
+impl<'de, N: NumBase + Deserialize<'de>> Deserialize<'de> for CycleKind<N> {
+    fn deserialize<D: Deserializer<'de>>(d: D) -> Result<Self, D::Error> {
+        let CycleKindData { size, limit } = CycleKindData::deserialize(d)?;
+        let size = positive(size, "size").map_err(D::Error::custom)?;
+        Ok(match limit {
+            Some(limit) => Self::limited(size, positive(limit, "limit").map_err(D::Error::custom)?),
+            None => Self::simple(size),
+        })
+    }
+}
+
+impl<R: PeriodRepresentation> Serialize for Period<R>
+where
+    R::Cycles: Serialize,
+{
+    fn serialize<S: Serializer>(&self, s: S) -> Result<S::Ok, S::Error> {
+        let mut state = s.serialize_struct("Period", 1)?;
+        state.serialize_field("size", &self.size().out())?;
+        state.end()
+    }
+}
+
+impl<'de, R: PeriodRepresentation> Deserialize<'de> for Period<R>
+where
+    R::Cycles: Deserialize<'de>,
+{
+    fn deserialize<D: Deserializer<'de>>(d: D) -> Result<Self, D::Error> {
+        let PeriodData { size } = PeriodData::deserialize(d)?;
+        Ok(Self::new(positive(size, "size").map_err(D::Error::custom)?))
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Systems
+////////////////////////////////////////////////////////////////////////////////
+
+#[derive(Deserialize)]
+struct SystemData<P, C> {
+    period: Option<P>,
+    factors: Vec<C>,
+}
+
+fn serialize_system<S, P, C>(s: S, name: &'static str, period: &P, factors: &[C]) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+    P: Serialize,
+    C: Serialize,
+{
+    let mut state = s.serialize_struct(name, 2)?;
+    state.serialize_field("period", period)?;
+    state.serialize_field("factors", factors)?;
+    state.end()
+}
+
+impl<R, C, const N: usize> SimpleMixed<R, C, N>
+where
+    R: PeriodRepresentation,
+    C: Cycle<R::Cycles, Cycles = Natural<R::Cycles>>,
+{
+    pub fn from_definition(period: Option<Period<R>>, factors: Vec<C>) -> Result<Self, DefinitionError> {
+        let mixed = Self::checked_from_factors(sized(factors)?).ok_or(DefinitionError::PeriodOverflow)?;
+        if let Some(Violation::DigitOverflow { index, .. }) = mixed.digit_overflows().first() {
+            return Err(DefinitionError::DigitOverflow { index: *index });
+        }
+        match period {
+            Some(period) if period.size() != mixed.period.size() => Err(DefinitionError::PeriodMismatch),
+            _ => Ok(mixed),
+        }
+    }
+}
+
+impl<R, C, const N: usize> Serialize for SimpleMixed<R, C, N>
+where
+    R: PeriodRepresentation,
+    R::Cycles: Serialize,
+    C: Cycle<R::Cycles, Cycles = Natural<R::Cycles>> + Serialize,
+{
+    fn serialize<S: Serializer>(&self, s: S) -> Result<S::Ok, S::Error> {
+        serialize_system(s, "SimpleMixed", &self.period, &self.factors)
+    }
+}
+
+impl<'de, R, C, const N: usize> Deserialize<'de> for SimpleMixed<R, C, N>
+where
+    R: PeriodRepresentation,
+    R::Cycles: Deserialize<'de>,
+    C: Cycle<R::Cycles, Cycles = Natural<R::Cycles>> + Deserialize<'de>,
+{
+    fn deserialize<D: Deserializer<'de>>(d: D) -> Result<Self, D::Error> {
+        let SystemData { period, factors } = SystemData::deserialize(d)?;
+        Self::from_definition(period, factors).map_err(D::Error::custom)
+    }
+}
+
+impl<R, C, const N: usize> SimpleFilter<R, C, N>
+where
+    R: PeriodRepresentation,
+    C: Cycle<R::Cycles, Cycles = Natural<R::Cycles>>,
+{
+    pub fn from_definition(period: Period<R>, factors: Vec<C>) -> Result<Self, DefinitionError> {
+        let factors: [C; N] = sized(factors)?;
+        let filter = Self::new(period, factors);
+        match filter.structural_violations().into_iter().next() {
+            None => Ok(filter),
+            Some(Violation::Oversized { index, .. }) => Err(DefinitionError::Oversized { index }),
+            Some(Violation::UnreachableLimit { index, .. }) => Err(DefinitionError::UnreachableLimit { index }),
+            Some(Violation::TruncatedLast { index, .. }) => Err(DefinitionError::TruncatedLast { index }),
+            Some(Violation::DigitOverflow { index, .. }) => Err(DefinitionError::DigitOverflow { index }),
+            Some(_) => unreachable!("Only winding finds other violations"),
+        }
+    }
+}
+
+impl<R, C, const N: usize> Serialize for SimpleFilter<R, C, N>
+where
+    R: PeriodRepresentation,
+    R::Cycles: Serialize,
+    C: Cycle<R::Cycles, Cycles = Natural<R::Cycles>> + Serialize,
+{
+    fn serialize<S: Serializer>(&self, s: S) -> Result<S::Ok, S::Error> {
+        serialize_system(s, "SimpleFilter", &self.period, &self.factors)
+    }
+}
+
+impl<'de, R, C, const N: usize> Deserialize<'de> for SimpleFilter<R, C, N>
+where
+    R: PeriodRepresentation,
+    R::Cycles: Deserialize<'de>,
+    C: Cycle<R::Cycles, Cycles = Natural<R::Cycles>> + Deserialize<'de>,
+{
+    fn deserialize<D: Deserializer<'de>>(d: D) -> Result<Self, D::Error> {
+        let SystemData { period, factors } = SystemData::deserialize(d)?;
+        let period = period.ok_or_else(|| D::Error::missing_field("period"))?;
+        Self::from_definition(period, factors).map_err(D::Error::custom)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Legends
+////////////////////////////////////////////////////////////////////////////////
+
+// Legends are read into owned names, since a borrowed one could only be read
+// from input that outlives it and has no escapes in the names
+
+#[derive(Deserialize)]
+struct MixedPointLegendData<const N: usize> {
+    cycle: String,
+    phase: OwnedPhaseLegend<N>,
+}
+
+impl<const N: usize> Serialize for PhaseLegend<'_, N> {
+    fn serialize<S: Serializer>(&self, s: S) -> Result<S::Ok, S::Error> {
+        let mut seq = s.serialize_seq(Some(N))?;
+        for name in self.names() {
+            seq.serialize_element(name)?;
+        }
+        seq.end()
+    }
+}
+
+impl<const N: usize> Serialize for OwnedPhaseLegend<N> {
+    fn serialize<S: Serializer>(&self, s: S) -> Result<S::Ok, S::Error> {
+        self.as_legend().serialize(s)
+    }
+}
+
+impl<'de, const N: usize> Deserialize<'de> for OwnedPhaseLegend<N> {
+    fn deserialize<D: Deserializer<'de>>(d: D) -> Result<Self, D::Error> {
+        let names = Vec::<String>::deserialize(d)?;
+        Ok(Self(sized(names).map_err(D::Error::custom)?))
+    }
+}
+
+impl<const N: usize> Serialize for MixedPointLegend<'_, N> {
+    fn serialize<S: Serializer>(&self, s: S) -> Result<S::Ok, S::Error> {
+        let mut state = s.serialize_struct("MixedPointLegend", 2)?;
+        state.serialize_field("cycle", self.cycle)?;
+        state.serialize_field("phase", &self.phase)?;
+        state.end()
+    }
+}
+
+impl<const N: usize> Serialize for OwnedMixedPointLegend<N> {
+    fn serialize<S: Serializer>(&self, s: S) -> Result<S::Ok, S::Error> {
+        self.as_legend().serialize(s)
+    }
+}
+
+impl<'de, const N: usize> Deserialize<'de> for OwnedMixedPointLegend<N> {
+    fn deserialize<D: Deserializer<'de>>(d: D) -> Result<Self, D::Error> {
+        let MixedPointLegendData { cycle, phase } = MixedPointLegendData::deserialize(d)?;
+        Ok(Self { cycle, phase })
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Points
+////////////////////////////////////////////////////////////////////////////////
+
+// A point written as its cycle under the legend's cycle name, and its phase
+// as a map from digit names to values, highest digit first
+pub struct MixedPointWithLegend<'a, const N: usize, P: Mixed<N>> {
+    pub point: BoundMixedPoint<'a, N, P>,
+    pub legend: &'a MixedPointLegend<'a, N>,
+}
+
+impl<'a, const N: usize, P: Mixed<N>> MixedPointWithLegend<'a, N, P> {
+    pub fn new(point: BoundMixedPoint<'a, N, P>, legend: &'a MixedPointLegend<'a, N>) -> Self {
+        Self { point, legend }
+    }
+}
+
+// Reads a `MixedPointWithLegend`, binding it to `schema` only if it is normal
+pub struct MixedPointSchema<'a, const N: usize, P: Mixed<N>> {
+    pub schema: &'a P,
+    pub legend: &'a MixedPointLegend<'a, N>,
+}
+
+impl<'a, const N: usize, P: Mixed<N>> MixedPointSchema<'a, N, P> {
+    pub fn new(schema: &'a P, legend: &'a MixedPointLegend<'a, N>) -> Self {
+        Self { schema, legend }
+    }
+}
+
+struct NamedPhase<'a, U: NumBase, const N: usize> {
+    phase: &'a Phase<U, N>,
+    legend: &'a PhaseLegend<'a, N>,
+}
+
+struct PhaseSchema<'a, U, const N: usize> {
+    legend: &'a PhaseLegend<'a, N>,
+    digits: PhantomData<U>,
+}
+
+impl<U: NumBase + Serialize, const N: usize> Serialize for NamedPhase<'_, U, N> {
+    fn serialize<S: Serializer>(&self, s: S) -> Result<S::Ok, S::Error> {
+        let mut map = s.serialize_map(Some(N))?;
+        for (name, digit) in self.legend.name(*self.phase).iter().rev() {
+            map.serialize_entry(name, &digit.out())?;
+        }
+        map.end()
+    }
+}
+
+impl<'de, U: NumBase + Deserialize<'de>, const N: usize> DeserializeSeed<'de> for PhaseSchema<'_, U, N> {
+    type Value = Phase<U, N>;
+
+    fn deserialize<D: Deserializer<'de>>(self, d: D) -> Result<Self::Value, D::Error> {
+        d.deserialize_map(self)
+    }
+}
+
+impl<'de, U: NumBase + Deserialize<'de>, const N: usize> Visitor<'de> for PhaseSchema<'_, U, N> {
+    type Value = Phase<U, N>;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "a map of the digits {:?}", self.legend.names())
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {
+        let mut digits: [Option<Natural<U>>; N] = [None; N];
+        while let Some(name) = map.next_key::<String>()? {
+            let index = self.legend.names().iter().position(|n| *n == name);
+            let Some(index) = index else {
+                return Err(A::Error::custom(format!("unknown digit '{}'", name)));
+            };
+            if digits[index].is_some() {
+                return Err(A::Error::custom(format!("duplicate digit '{}'", name)));
+            }
+            let digit = Natural::rep(map.next_value::<U>()?);
+            digits[index] = Some(digit.ok_or_else(|| A::Error::custom(format!("'{}' must not be negative", name)))?);
+        }
+        let mut phase = [Natural::ZERO; N];
+        for (k, digit) in digits.iter().enumerate() {
+            phase[k] = digit.ok_or_else(|| A::Error::custom(format!("missing digit '{}'", self.legend.names()[k])))?;
+        }
+        Ok(phase)
+    }
+}
+
+impl<const N: usize, L, P> Serialize for MixedPointWithLegend<'_, N, P>
+where
+    L: NumBase + Serialize,
+    P: Mixed<N, Cycles = Integer<L>>,
+    P::U: Serialize,
+{
+    fn serialize<S: Serializer>(&self, s: S) -> Result<S::Ok, S::Error> {
+        let point = &self.point.point;
+        let mut map = s.serialize_map(Some(2))?;
+        map.serialize_entry(self.legend.cycle, &point.cycle.out())?;
+        map.serialize_entry(
+            "phase",
+            &NamedPhase {
+                phase: &point.phase,
+                legend: &self.legend.phase,
+            },
+        )?;
+        map.end()
+    }
+}
+
+impl<'de, 'a, const N: usize, L, P> DeserializeSeed<'de> for MixedPointSchema<'a, N, P>
+where
+    L: NumBase + Deserialize<'de>,
+    P: Mixed<N, Cycles = Integer<L>>,
+    P::U: Deserialize<'de>,
+{
+    type Value = MixedPointWithLegend<'a, N, P>;
+
+    fn deserialize<D: Deserializer<'de>>(self, d: D) -> Result<Self::Value, D::Error> {
+        d.deserialize_map(self)
+    }
+}
+
+impl<'de, 'a, const N: usize, L, P> Visitor<'de> for MixedPointSchema<'a, N, P>
+where
+    L: NumBase + Deserialize<'de>,
+    P: Mixed<N, Cycles = Integer<L>>,
+    P::U: Deserialize<'de>,
+{
+    type Value = MixedPointWithLegend<'a, N, P>;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "a map with '{}' and 'phase'", self.legend.cycle)
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {
+        let (mut cycle, mut phase) = (None, None);
+        while let Some(name) = map.next_key::<String>()? {
+            if name == self.legend.cycle && cycle.is_none() {
+                cycle = Some(Integer::new(map.next_value::<L>()?));
+            } else if name == "phase" && phase.is_none() {
+                phase = Some(map.next_value_seed(PhaseSchema {
+                    legend: &self.legend.phase,
+                    digits: PhantomData,
+                })?);
+            } else {
+                return Err(A::Error::custom(format!("unexpected field '{}'", name)));
+            }
+        }
+        let cycle = cycle.ok_or_else(|| A::Error::custom(format!("missing field '{}'", self.legend.cycle)))?;
+        let phase = phase.ok_or_else(|| A::Error::missing_field("phase"))?;
+        let point = self.schema.point(cycle, phase);
+        let point = point.ok_or_else(|| A::Error::custom("phase does not fit the schema"))?;
+        Ok(MixedPointWithLegend::new(point, self.legend))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use numburs::{int, integral::testing::*, pos, Integer};
+    use serde::de::DeserializeSeed;
+
+    use super::{DefinitionError, MixedPointSchema, MixedPointWithLegend};
+    use crate::{
+        cycle::{cycle_kind::CycleKind, limited_cycle::LimitedCycle, period::Period, simple_cycle::SimpleCycle},
+        filter::simple::SimpleFilter,
+        mixed::{
+            simple::SimpleMixed,
+            types::{Mixed, MixedPoint, MixedPointLegend, OwnedMixedPointLegend},
+        },
+        IdentityPeriodRepresentation, WideningPeriodRepresentation,
+    };
+
+    type Rep = IdentityPeriodRepresentation<i64>;
+    type MixedType = SimpleMixed<Rep, SimpleCycle<i64>, 3>;
+    type FilterType = SimpleFilter<Rep, LimitedCycle<i64>, 2>;
+
+    #[test]
+    fn mixed_round_trip() {
+        let mixed = MixedType::from_sizes([8, 2, 16]);
+        let json = serde_json::to_string(&mixed).unwrap();
+        assert_eq!(
+            r#"{"period":{"size":256},"factors":[{"size":8},{"size":2},{"size":16}]}"#,
+            json
+        );
+        assert_eq!(mixed, serde_json::from_str(&json).unwrap());
+    }
+
+    #[test]
+    fn mixed_without_period() {
+        let mixed: MixedType = serde_json::from_str(r#"{"factors":[{"size":8},{"size":2},{"size":16}]}"#).unwrap();
+        assert_eq!(MixedType::from_sizes([8, 2, 16]), mixed);
+    }
+
+    #[test]
+    fn mixed_validation() {
+        let mismatch = serde_json::from_str::<MixedType>(
+            r#"{"period":{"size":255},"factors":[{"size":8},{"size":2},{"size":16}]}"#,
+        );
+        assert!(mismatch.unwrap_err().to_string().contains("does not match"));
+        let short = serde_json::from_str::<MixedType>(r#"{"factors":[{"size":8},{"size":2}]}"#);
+        assert!(short.unwrap_err().to_string().contains("expected 3 entries, found 2"));
+        let zero = serde_json::from_str::<MixedType>(r#"{"factors":[{"size":8},{"size":0},{"size":16}]}"#);
+        assert!(zero.unwrap_err().to_string().contains("'size' must be positive"));
+        type Narrow = SimpleMixed<WideningPeriodRepresentation<u8, i64>, SimpleCycle<i64>, 2>;
+        assert_eq!(
+            Err(DefinitionError::DigitOverflow { index: 1 }),
+            Narrow::from_definition(None, vec![SimpleCycle::new(P8), SimpleCycle::new(pos!(300))])
+        );
+        let huge = SimpleCycle::new(pos!(1_i64 << 32));
+        assert_eq!(
+            Err(DefinitionError::PeriodOverflow),
+            MixedType::from_definition(None, vec![huge, huge, SimpleCycle::new(P2)])
+        );
+    }
+
+    #[test]
+    fn filter_round_trip() {
+        let filter: FilterType = SimpleFilter::new(
+            Period::new(pos!(50)),
+            [
+                LimitedCycle::new(pos!(10), pos!(3)),
+                LimitedCycle::new(pos!(3), pos!(2)),
+            ],
+        );
+        let json = serde_json::to_string(&filter).unwrap();
+        assert_eq!(
+            r#"{"period":{"size":50},"factors":[{"size":10,"limit":3},{"size":3,"limit":2}]}"#,
+            json
+        );
+        assert_eq!(filter, serde_json::from_str(&json).unwrap());
+    }
+
+    #[test]
+    fn cycle_kind_filter_round_trip() {
+        let filter: SimpleFilter<Rep, CycleKind<i64>, 2> = SimpleFilter::new(
+            Period::new(pos!(50)),
+            [CycleKind::limited(pos!(10), pos!(3)), CycleKind::simple(pos!(5))],
+        );
+        let json = serde_json::to_string(&filter).unwrap();
+        assert_eq!(
+            r#"{"period":{"size":50},"factors":[{"size":10,"limit":3},{"size":5}]}"#,
+            json
+        );
+        assert_eq!(filter, serde_json::from_str(&json).unwrap());
+    }
+
+    #[test]
+    fn filter_validation() {
+        // The last unit of the first factor spans 20, so only a factor past that is oversized
+        assert_eq!(
+            Err(DefinitionError::Oversized { index: 1 }),
+            FilterType::from_definition(
+                Period::new(pos!(50)),
+                vec![
+                    LimitedCycle::new(pos!(10), pos!(3)),
+                    LimitedCycle::new(pos!(21), pos!(2))
+                ]
+            )
+        );
+        assert_eq!(
+            Err(DefinitionError::UnreachableLimit { index: 1 }),
+            FilterType::from_definition(
+                Period::new(pos!(50)),
+                vec![
+                    LimitedCycle::new(pos!(10), pos!(3)),
+                    LimitedCycle::new(pos!(11), pos!(2))
+                ]
+            )
+        );
+        assert_eq!(
+            Err(DefinitionError::UnreachableLimit { index: 0 }),
+            FilterType::from_definition(
+                Period::new(pos!(50)),
+                vec![
+                    LimitedCycle::new(pos!(10), pos!(6)),
+                    LimitedCycle::new(pos!(3), pos!(2))
+                ]
+            )
+        );
+        assert_eq!(
+            Err(DefinitionError::TruncatedLast { index: 0 }),
+            FilterType::from_definition(
+                Period::new(pos!(34)),
+                vec![
+                    LimitedCycle::new(pos!(10), pos!(3)),
+                    LimitedCycle::new(pos!(3), pos!(2))
+                ]
+            )
+        );
+        let json = r#"{"period":{"size":34},"factors":[{"size":10,"limit":3},{"size":3,"limit":2}]}"#;
+        assert!(serde_json::from_str::<FilterType>(json).is_err());
+        let missing = serde_json::from_str::<FilterType>(r#"{"factors":[{"size":10,"limit":3},{"size":3,"limit":2}]}"#);
+        assert!(missing.is_err());
+    }
+
+    #[test]
+    fn legend_round_trip() {
+        let legend = MixedPointLegend::with_names("aeon", ["unade", "octade", "hexade"]);
+        let json = serde_json::to_string(&legend).unwrap();
+        assert_eq!(r#"{"cycle":"aeon","phase":["unade","octade","hexade"]}"#, json);
+        let read: OwnedMixedPointLegend<3> = serde_json::from_str(&json).unwrap();
+        assert_eq!(legend, read.as_legend());
+        assert_eq!(json, serde_json::to_string(&read).unwrap());
+        assert!(serde_json::from_str::<OwnedMixedPointLegend<2>>(&json).is_err());
+
+        // Escaped names and readers leave nothing to borrow from
+        let escaped = r#"{"cycle":"a\u0065on","phase":["unade","octade","hexade"]}"#;
+        let read: OwnedMixedPointLegend<3> = serde_json::from_reader(escaped.as_bytes()).unwrap();
+        assert_eq!(legend, read.as_legend());
+    }
+
+    #[test]
+    fn point_round_trip() {
+        let mixed = MixedType::from_sizes([8, 2, 16]);
+        let names = [String::from("unade"), String::from("octade"), String::from("hexade")];
+        let cycle = String::from("aeon");
+        let legend = MixedPointLegend::with_names(&cycle, [&names[0], &names[1], &names[2]].map(|n| n.as_str()));
+
+        let point = mixed.wind(int!(603));
+        let json = serde_json::to_string(&MixedPointWithLegend::new(point, &legend)).unwrap();
+        assert_eq!(r#"{"aeon":2,"phase":{"hexade":5,"octade":1,"unade":3}}"#, json);
+
+        let read = MixedPointSchema::new(&mixed, &legend)
+            .deserialize(&mut serde_json::Deserializer::from_str(&json))
+            .unwrap();
+        assert_eq!(MixedPoint::new(I2, [N3, N1, N5]), read.point.point);
+        assert_eq!(int!(603), mixed.unwind(&read.point.point));
+    }
+
+    #[test]
+    fn point_validation() {
+        let mixed = MixedType::from_sizes([8, 2, 16]);
+        let legend = MixedPointLegend::with_names("aeon", ["unade", "octade", "hexade"]);
+        let read = |json: &str| {
+            MixedPointSchema::new(&mixed, &legend)
+                .deserialize(&mut serde_json::Deserializer::from_str(json))
+                .map(|p| p.point.point)
+                .map_err(|e| e.to_string())
+        };
+        assert_eq!(
+            Ok(MixedPoint::new(-I1, [N0, N0, N0])),
+            read(r#"{"phase":{"unade":0,"hexade":0,"octade":0},"aeon":-1}"#)
+        );
+        assert!(read(r#"{"aeon":0,"phase":{"unade":8,"octade":0,"hexade":0}}"#)
+            .unwrap_err()
+            .contains("does not fit"));
+        assert!(read(r#"{"aeon":0,"phase":{"unade":-1,"octade":0,"hexade":0}}"#)
+            .unwrap_err()
+            .contains("'unade' must not be negative"));
+        assert!(read(r#"{"aeon":0,"phase":{"unade":0,"hexade":0}}"#)
+            .unwrap_err()
+            .contains("missing digit 'octade'"));
+        assert!(
+            read(r#"{"aeon":0,"phase":{"unade":0,"octade":0,"hexade":0,"other":0}}"#)
+                .unwrap_err()
+                .contains("unknown digit 'other'")
+        );
+        assert!(read(r#"{"phase":{"unade":0,"octade":0,"hexade":0}}"#)
+            .unwrap_err()
+            .contains("missing field 'aeon'"));
+    }
+}
@@ -0,0 +1,155 @@
+// Declares a mixed system from its digit names and sizes, lowest first:
+//
+//     mixed_system!(Clock { edge: 2, event: 64, moment: 64, beat: 8, rhythm: 16 });
+//
+// generates `ClockType`, the system `CLOCK`, its legend `CLOCK_LEGEND` and a
+// point `Clock` with a getter per digit, which serializes with the legend.
+// The cycle is named "cycle" and counted in i64 unless given, as in
+// `mixed_system!(Depth(aeon): i32 { .. })`.
+#[macro_export]
+macro_rules! mixed_system {
+    ($(#[$meta:meta])* $name:ident ($cycle:ident): $t:ty { $($digit:ident: $size:expr),+ $(,)? }) => {
+        $crate::mixed_system!(@define $(#[$meta])* $name, $cycle, $t, $($digit: $size),+);
+    };
+    ($(#[$meta:meta])* $name:ident ($cycle:ident) { $($digit:ident: $size:expr),+ $(,)? }) => {
+        $crate::mixed_system!(@define $(#[$meta])* $name, $cycle, i64, $($digit: $size),+);
+    };
+    ($(#[$meta:meta])* $name:ident: $t:ty { $($digit:ident: $size:expr),+ $(,)? }) => {
+        $crate::mixed_system!(@define $(#[$meta])* $name, cycle, $t, $($digit: $size),+);
+    };
+    ($(#[$meta:meta])* $name:ident { $($digit:ident: $size:expr),+ $(,)? }) => {
+        $crate::mixed_system!(@define $(#[$meta])* $name, cycle, i64, $($digit: $size),+);
+    };
+
+    (@define $(#[$meta:meta])* $name:ident, $cycle:ident, $t:ty, $($digit:ident: $size:expr),+) => {
+        $crate::paste::paste! {
+            pub type [<$name Type>] = $crate::SimpleMixed<
+                $crate::IdentityPeriodRepresentation<$t>,
+                $crate::SimpleCycle<$t>,
+                { [$(stringify!($digit)),+].len() },
+            >;
+
+            pub const [<$name:snake:upper>]: [<$name Type>] = [<$name Type>]::from_sizes([$($size),+]);
+
+            pub const [<$name:snake:upper _LEGEND>]: $crate::MixedPointLegend<'static, { [$(stringify!($digit)),+].len() }> =
+                $crate::MixedPointLegend::with_names(stringify!($cycle), [$(stringify!($digit)),+]);
+
+            $(#[$meta])*
+            #[derive(Debug, PartialEq, Eq, Clone)]
+            pub struct $name(pub $crate::BoundMixedPoint<'static, { [$(stringify!($digit)),+].len() }, [<$name Type>]>);
+
+            impl $name {
+                // None unless every digit is within its size
+                pub fn new(
+                    cycle: $crate::numburs::Integer<$t>,
+                    $($digit: $crate::numburs::Natural<$t>),+
+                ) -> Option<Self> {
+                    $crate::Mixed::point(&[<$name:snake:upper>], cycle, [$($digit),+]).map(Self)
+                }
+
+                pub fn wind(total: $crate::numburs::Integer<$t>) -> Self {
+                    Self($crate::Mixed::wind(&[<$name:snake:upper>], total))
+                }
+
+                pub fn total(&self) -> $crate::numburs::Integer<$t> {
+                    $crate::Mixed::unwind(&[<$name:snake:upper>], &self.0.point)
+                }
+
+                pub fn $cycle(&self) -> $crate::numburs::Integer<$t> {
+                    self.0.point.cycle
+                }
+
+                $crate::mixed_system!(@getters $t, 0usize, $($digit),+);
+            }
+
+            impl $crate::serde::Serialize for $name {
+                fn serialize<S: $crate::serde::Serializer>(&self, s: S) -> Result<S::Ok, S::Error> {
+                    $crate::serde::Serialize::serialize(
+                        &$crate::MixedPointWithLegend::new(self.0.clone(), &[<$name:snake:upper _LEGEND>]),
+                        s,
+                    )
+                }
+            }
+
+            impl<'de> $crate::serde::Deserialize<'de> for $name {
+                fn deserialize<D: $crate::serde::Deserializer<'de>>(d: D) -> Result<Self, D::Error> {
+                    let schema = $crate::MixedPointSchema::new(&[<$name:snake:upper>], &[<$name:snake:upper _LEGEND>]);
+                    $crate::serde::de::DeserializeSeed::deserialize(schema, d).map(|read| Self(read.point))
+                }
+            }
+        }
+    };
+
+    (@getters $t:ty, $index:expr, $digit:ident $(, $rest:ident)*) => {
+        pub fn $digit(&self) -> $crate::numburs::Natural<$t> {
+            self.0.point.phase[$index]
+        }
+
+        $crate::mixed_system!(@getters $t, $index + 1, $($rest),*);
+    };
+    (@getters $t:ty, $index:expr,) => {};
+}
+
+#[cfg(test)]
+mod tests {
+    use numburs::{int, integral::testing::*, nat, Integer, LowerBoundedRep, Positive};
+
+    use crate::mixed::{simple::SimpleMixed, types::Mixed};
+
+    mixed_system!(Clock {
+        edge: 2,
+        event: 64,
+        moment: 64,
+        beat: 8,
+        rhythm: 16
+    });
+
+    mixed_system!(
+        // A leading comment or attribute goes to the point
+        Depth(aeon): i32 { unade: 8, octade: 2, hexade: 16 }
+    );
+
+    #[test]
+    fn generated_system() {
+        let sizes = [2, 64, 64, 8, 16].map(Positive::at_least);
+        assert_eq!(SimpleMixed::from_simple_factors(sizes), CLOCK);
+        assert_eq!(
+            ["edge", "event", "moment", "beat", "rhythm"],
+            *CLOCK_LEGEND.phase.names()
+        );
+        assert_eq!("cycle", CLOCK_LEGEND.cycle);
+        assert_eq!("aeon", DEPTH_LEGEND.cycle);
+        assert_eq!(int!(256), DEPTH.unwind(&DEPTH.wind(int!(256)).point));
+    }
+
+    #[test]
+    fn getters() {
+        let clock = Clock::wind(int!(2 * 64 * 64 * 8 * 16 + 2 * 64 + 3));
+        assert_eq!(I1, clock.cycle());
+        assert_eq!(N1, clock.edge());
+        assert_eq!(N1, clock.event());
+        assert_eq!(N1, clock.moment());
+        assert_eq!(N0, clock.beat());
+        assert_eq!(N0, clock.rhythm());
+        assert_eq!(int!(2 * 64 * 64 * 8 * 16 + 2 * 64 + 3), clock.total());
+        assert_eq!(Some(clock), Clock::new(I1, N1, N1, N1, N0, N0));
+
+        let depth = Depth::new(Integer::new(-1), nat!(7), nat!(1), nat!(15)).unwrap();
+        assert_eq!(Integer::new(-1), depth.aeon());
+        assert_eq!(
+            [nat!(7), nat!(1), nat!(15)],
+            [depth.unade(), depth.octade(), depth.hexade()]
+        );
+        assert_eq!(Integer::new(-1), depth.total());
+        assert!(Depth::new(Integer::new(0), nat!(8), nat!(0), nat!(0)).is_none());
+    }
+
+    #[test]
+    fn serde_round_trip() {
+        let depth = Depth::wind(Integer::new(2 * 256 + 5 * 16 + 8 + 3));
+        let json = serde_json::to_string(&depth).unwrap();
+        assert_eq!(r#"{"aeon":2,"phase":{"hexade":5,"octade":1,"unade":3}}"#, json);
+        assert_eq!(depth, serde_json::from_str(&json).unwrap());
+        assert!(serde_json::from_str::<Depth>(r#"{"aeon":2,"phase":{"hexade":5,"octade":2,"unade":3}}"#).is_err());
+    }
+}
@@ -0,0 +1,78 @@
+use core::fmt::{self, Display};
+
+use derive_more::Constructor;
+use numburs::{Natural, NumBase, Positive};
+
+use crate::cycle::types::CyclePoint;
+
+// One division of `total` by a cycle of `size`, where `clamped` marks a phase
+// left at or beyond the size because the cycle count hit its limit
+#[derive(Debug, PartialEq, Eq, Constructor, Clone)]
+pub struct TraceStep<U: NumBase, Cycles> {
+    pub size: Positive<U>,
+    pub total: Cycles,
+    pub point: CyclePoint<U, Cycles>,
+    pub clamped: bool,
+}
+
+// The period step followed by the step at each factor, in factor order
+#[derive(Debug, PartialEq, Eq, Constructor, Clone)]
+pub struct Trace<U: NumBase, Cycles> {
+    pub period: TraceStep<U, Cycles>,
+    pub factors: Vec<TraceStep<U, Natural<U>>>,
+}
+
+impl<U: NumBase + Display, Cycles: Display> Display for TraceStep<U, Cycles> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} = {} * {} + {}",
+            self.total, self.point.cycle, self.size, self.point.phase
+        )?;
+        if self.clamped {
+            write!(f, " (clamped)")?;
+        }
+        Ok(())
+    }
+}
+
+impl<U: NumBase + Display, Cycles: Display> Display for Trace<U, Cycles> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "period: {}", self.period)?;
+        for (k, step) in self.factors.iter().enumerate() {
+            write!(f, "\nfactor {}: {}", k, step)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use numburs::{integral::testing::*, nat, pos};
+
+    use crate::cycle::{
+        limited_cycle::LimitedCycle,
+        simple_cycle::SimpleCycle,
+        types::{Cycle, CyclePoint},
+    };
+
+    #[test]
+    fn cycle_trace() {
+        let step = SimpleCycle::new(P3).wind_trace(&nat!(11));
+        assert_eq!(CyclePoint::new(N3, N2), step.point);
+        assert!(!step.clamped);
+        assert_eq!("N(11) = N(3) * P(3) + N(2)", step.to_string());
+    }
+
+    #[test]
+    fn limited_cycle_trace() {
+        let cycle = LimitedCycle::new(P3, P2);
+        let step = cycle.wind_trace(&nat!(11));
+        assert_eq!(CyclePoint::new(N2, N5), step.point);
+        assert!(step.clamped);
+        assert_eq!("N(11) = N(2) * P(3) + N(5) (clamped)", step.to_string());
+        assert_eq!(step, cycle.unwind_trace(CyclePoint::new(N2, N5)));
+        assert!(!cycle.wind_trace(&nat!(8)).clamped);
+        assert_eq!(pos!(3), step.size);
+    }
+}
@@ -8,15 +8,33 @@ pub type Phase<U, const N: usize> = [Natural<U>; N];
 pub type Factors<C, const N: usize> = [C; N];
 pub type NamedPhase<'a, U, const N: usize> = [(&'a str, Natural<U>); N];
 
-#[derive(Debug, PartialEq, Eq, Constructor, Clone)]
+#[derive(Debug, PartialEq, Eq, Clone)]
 pub struct PhaseLegend<'a, const N: usize>([&'a str; N]);
 
 impl<'a, const N: usize> PhaseLegend<'a, N> {
+    pub const fn new(names: [&'a str; N]) -> Self {
+        Self(names)
+    }
+
+    pub fn names(&self) -> &[&'a str; N] {
+        &self.0
+    }
+
     pub fn name<U: NumBase>(&self, phase: Phase<U, N>) -> NamedPhase<'a, U, N> {
         array::from_fn(|i| (self.0[i], phase[i]))
     }
 }
 
+// A legend holding its names, as read from a serialized one
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct OwnedPhaseLegend<const N: usize>(pub [String; N]);
+
+impl<const N: usize> OwnedPhaseLegend<N> {
+    pub fn as_legend(&self) -> PhaseLegend<'_, N> {
+        PhaseLegend::new(self.0.each_ref().map(String::as_str))
+    }
+}
+
 #[derive(Debug, PartialEq, Eq, Constructor, Clone)]
 pub struct PhaseWithLegend<'a, 'b, U: NumBase, const N: usize> {
     pub phase: Phase<U, N>,
@@ -36,7 +54,7 @@ impl<U: NumBase + Serialize, const N: usize> Serialize for PhaseWithLegend<'_, '
 
 #[cfg(test)]
 mod test {
-    use numburs::{nat, LowerBoundedRep, Natural};
+    use numburs::nat;
 
     use super::{NamedPhase, Phase, PhaseLegend};
 
@@ -0,0 +1,459 @@
+use core::{
+    cmp::{max, min},
+    fmt::{self, Display},
+};
+
+use num_traits::{Bounded, CheckedMul, ConstOne};
+use numburs::{ConstrainedRep, HasOne, HasZero, Integer, Natural, NumBase, Positive};
+
+use crate::{
+    cycle::{period::PeriodRepresentation, types::Cycle},
+    filter::{simple::SimpleFilter, types::Filter},
+    mixed::{simple::SimpleMixed, types::Mixed},
+};
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum Violation<U: NumBase, C: NumBase> {
+    PeriodMismatch {
+        period: Positive<U>,
+        expected: Positive<U>,
+    },
+    // The product of the factor sizes does not fit, so cannot be the period
+    PeriodOverflow {
+        period: Positive<U>,
+    },
+    Oversized {
+        index: usize,
+        size: Positive<U>,
+        span: Positive<U>,
+    },
+    UnreachableLimit {
+        index: usize,
+        limit: Positive<U>,
+        reached: Natural<U>,
+    },
+    TruncatedLast {
+        index: usize,
+        size: Positive<U>,
+        last: Natural<U>,
+    },
+    // Digit `index` reaches a value the phase type cannot hold, where index N
+    // of a filter is its remainder
+    DigitOverflow {
+        index: usize,
+        digit: Natural<U>,
+    },
+    NotNorm {
+        total: Integer<C>,
+    },
+    RoundTrip {
+        total: Integer<C>,
+        unwound: Integer<C>,
+    },
+}
+
+impl<U: NumBase + Display, C: NumBase + Display> Display for Violation<U, C> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Violation::PeriodMismatch { period, expected } => {
+                write!(
+                    f,
+                    "period {} does not match the product of factor sizes {}",
+                    period, expected
+                )
+            }
+            Violation::PeriodOverflow { period } => {
+                write!(
+                    f,
+                    "period {} is not the product of factor sizes, which overflows",
+                    period
+                )
+            }
+            Violation::Oversized { index, size, span } => {
+                write!(
+                    f,
+                    "factor {} of size {} does not fit in a span of {}",
+                    index, size, span
+                )
+            }
+            Violation::UnreachableLimit { index, limit, reached } => {
+                write!(
+                    f,
+                    "factor {} never reaches its limit {}, only {}",
+                    index, limit, reached
+                )
+            }
+            Violation::TruncatedLast { index, size, last } => {
+                write!(
+                    f,
+                    "factor {} ends the period on a last cycle of {}, short of its size {}",
+                    index, last, size
+                )
+            }
+            Violation::DigitOverflow { index, digit } => {
+                write!(f, "digit {} reaches {}, which the phase type cannot hold", index, digit)
+            }
+            Violation::NotNorm { total } => write!(f, "winding {} gives a point that is not normal", total),
+            Violation::RoundTrip { total, unwound } => write!(f, "winding {} unwinds to {}", total, unwound),
+        }
+    }
+}
+
+// Which totals of one period to wind and unwind
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Coverage<C: NumBase> {
+    Exhaustive,
+    Stride(Positive<C>),
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Report<U: NumBase, C: NumBase> {
+    pub checked: usize,
+    pub violations: Vec<Violation<U, C>>,
+}
+
+impl<U: NumBase, C: NumBase> Report<U, C> {
+    pub fn is_valid(&self) -> bool {
+        self.violations.is_empty()
+    }
+}
+
+// Runs `check` on the totals in [0, period) picked by `coverage`, always
+// including the last one
+fn check_period<U: NumBase, C: NumBase>(
+    report: &mut Report<U, C>,
+    period: Positive<C>,
+    coverage: Coverage<C>,
+    check: impl Fn(Integer<C>) -> Option<Violation<U, C>>,
+) {
+    let step = match coverage {
+        Coverage::Exhaustive => Positive::ONE,
+        Coverage::Stride(stride) => stride,
+    };
+    let last = Integer::new(period.out() - C::ONE);
+    let mut total = Integer::ZERO;
+    loop {
+        report.checked += 1;
+        report.violations.extend(check(total));
+        if total == last {
+            break;
+        }
+        total = min(total + step, last);
+    }
+}
+
+fn fits_phase<R: PeriodRepresentation>(digit: Natural<R::Cycles>) -> bool {
+    digit <= R::embed(Natural::new_unchecked(R::Phase::max_value()))
+}
+
+fn round_trip<U: NumBase, C: NumBase>(total: Integer<C>, norm: bool, unwound: Integer<C>) -> Option<Violation<U, C>> {
+    if !norm {
+        Some(Violation::NotNorm { total })
+    } else if unwound != total {
+        Some(Violation::RoundTrip { total, unwound })
+    } else {
+        None
+    }
+}
+
+impl<R, C, const N: usize> SimpleMixed<R, C, N>
+where
+    R: PeriodRepresentation,
+    C: Cycle<R::Cycles, Cycles = Natural<R::Cycles>>,
+{
+    pub fn validate(&self, coverage: Coverage<R::Cycles>) -> Report<R::Cycles, R::Cycles> {
+        let mut report = Report {
+            checked: 0,
+            violations: Vec::new(),
+        };
+        let expected = self
+            .factors
+            .iter()
+            .try_fold(R::Cycles::ONE, |p, c| p.checked_mul(&c.size().out()));
+        match expected {
+            None => report.violations.push(Violation::PeriodOverflow {
+                period: self.period.size(),
+            }),
+            Some(expected) if expected != self.period.size().out() => {
+                report.violations.push(Violation::PeriodMismatch {
+                    period: self.period.size(),
+                    expected: Positive::new_unchecked(expected),
+                })
+            }
+            Some(_) => {}
+        }
+
+        let overflows = self.digit_overflows();
+        if !overflows.is_empty() {
+            // Winding would fail on the first digit out of range
+            report.violations.extend(overflows);
+            return report;
+        }
+        check_period(&mut report, self.period.size(), coverage, |total| {
+            let point = self.wind_inner(&total);
+            round_trip(total, self.is_norm(&point), self.unwind(&point))
+        });
+        report
+    }
+
+    // The factors whose greatest digit does not fit the phase type
+    pub fn digit_overflows(&self) -> Vec<Violation<R::Cycles, R::Cycles>> {
+        let mut violations = Vec::new();
+        for (index, factor) in self.factors.iter().enumerate() {
+            let digit = Natural::new_unchecked(factor.size().out() - R::Cycles::ONE);
+            if !fits_phase::<R>(digit) {
+                violations.push(Violation::DigitOverflow { index, digit });
+            }
+        }
+        violations
+    }
+}
+
+impl<R, C, const N: usize> SimpleFilter<R, C, N>
+where
+    R: PeriodRepresentation,
+    C: Cycle<R::Cycles, Cycles = Natural<R::Cycles>>,
+{
+    pub fn validate(&self, coverage: Coverage<R::Cycles>) -> Report<R::Cycles, R::Cycles> {
+        let mut report = Report {
+            checked: 0,
+            violations: self.structural_violations(),
+        };
+        if report
+            .violations
+            .iter()
+            .any(|v| matches!(v, Violation::DigitOverflow { .. }))
+        {
+            // Winding would fail on the first digit out of range
+            return report;
+        }
+        check_period(&mut report, self.period.size(), coverage, |total| {
+            let point = self.wind_inner(&total);
+            round_trip(total, self.is_norm(&point), self.unwind(&point))
+        });
+        report
+    }
+
+    // The violations found from the factors alone, without winding any totals
+    pub fn structural_violations(&self) -> Vec<Violation<R::Cycles, R::Cycles>> {
+        let mut violations = Vec::new();
+        // The span is the number of distinct phases handed down to a factor,
+        // and the tail the number handed down by the very last cycle
+        let mut span = self.period.size();
+        let mut tail = self.period.size();
+        for (index, factor) in self.factors.iter().enumerate() {
+            let size = factor.size();
+            if size > span {
+                violations.push(Violation::Oversized { index, size, span });
+                continue;
+            }
+            let last = factor.wind(&Natural::new_unchecked(span.out() - R::Cycles::ONE)).point;
+            let end = factor.wind(&Natural::new_unchecked(tail.out() - R::Cycles::ONE)).point;
+            if !fits_phase::<R>(last.cycle) {
+                violations.push(Violation::DigitOverflow {
+                    index,
+                    digit: last.cycle,
+                });
+            }
+            if let Some(limit) = factor.cycle_limit() {
+                if last.cycle < limit.into() {
+                    violations.push(Violation::UnreachableLimit {
+                        index,
+                        limit,
+                        reached: last.cycle,
+                    });
+                } else if end.cycle < limit.into() || end.phase.out() + R::Cycles::ONE < size.out() {
+                    // The period has to cover every full cycle up to the limit
+                    // and then a whole last unit
+                    let last = if end.cycle < limit.into() {
+                        Natural::ZERO
+                    } else {
+                        Natural::new_unchecked(end.phase.out() + R::Cycles::ONE)
+                    };
+                    violations.push(Violation::TruncatedLast { index, size, last });
+                }
+            }
+            tail = Positive::new_unchecked(end.phase.out() + R::Cycles::ONE);
+            // At least size - 1 + 1, so positive
+            span = Positive::new_unchecked(max(last.phase.out(), size.out() - R::Cycles::ONE) + R::Cycles::ONE);
+        }
+        let remainder = Natural::new_unchecked(span.out() - R::Cycles::ONE);
+        if !fits_phase::<R>(remainder) {
+            violations.push(Violation::DigitOverflow {
+                index: N,
+                digit: remainder,
+            });
+        }
+        violations
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use numburs::{int, integral::testing::*, nat, pos, Integer};
+
+    use super::{Coverage, Violation};
+    use crate::{
+        cycle::{cycle_kind::CycleKind, limited_cycle::LimitedCycle, period::Period, simple_cycle::SimpleCycle},
+        filter::simple::SimpleFilter,
+        mixed::simple::SimpleMixed,
+        IdentityPeriodRepresentation, WideningPeriodRepresentation,
+    };
+
+    type Rep = IdentityPeriodRepresentation<i64>;
+    type MixedType = SimpleMixed<Rep, SimpleCycle<i64>, 2>;
+
+    #[test]
+    fn valid_mixed() {
+        let report = MixedType::from_sizes([4, 6]).validate(Coverage::Exhaustive);
+        assert!(report.is_valid());
+        assert_eq!(24, report.checked);
+
+        let report = MixedType::from_sizes([4, 6]).validate(Coverage::Stride(P5));
+        assert!(report.is_valid());
+        assert_eq!(6, report.checked);
+    }
+
+    #[test]
+    fn mismatched_mixed() {
+        let mixed = MixedType::new(Period::new(pos!(20)), [SimpleCycle::new(P4), SimpleCycle::new(P6)]);
+        let report = mixed.validate(Coverage::Exhaustive);
+        assert_eq!(
+            Some(&Violation::PeriodMismatch {
+                period: pos!(20),
+                expected: pos!(24)
+            }),
+            report.violations.first()
+        );
+        assert_eq!(1, report.violations.len());
+
+        let mixed = MixedType::new(Period::new(pos!(30)), [SimpleCycle::new(P4), SimpleCycle::new(P6)]);
+        let report = mixed.validate(Coverage::Exhaustive);
+        assert_eq!(
+            Some(&Violation::RoundTrip {
+                total: int!(24),
+                unwound: I0
+            }),
+            report.violations.get(1)
+        );
+        assert_eq!(7, report.violations.len());
+    }
+
+    #[test]
+    fn valid_filter() {
+        let filter = SimpleFilter::<Rep, _, 2>::new(
+            Period::new(pos!(50)),
+            [
+                LimitedCycle::new(pos!(10), pos!(3)),
+                LimitedCycle::new(pos!(3), pos!(2)),
+            ],
+        );
+        let report = filter.validate(Coverage::Exhaustive);
+        assert!(report.is_valid());
+        assert_eq!(50, report.checked);
+    }
+
+    #[test]
+    fn invalid_filter() {
+        let filter = SimpleFilter::<Rep, _, 2>::new(
+            Period::new(pos!(50)),
+            [
+                LimitedCycle::new(pos!(10), pos!(6)),
+                LimitedCycle::new(pos!(30), pos!(2)),
+            ],
+        );
+        let report = filter.validate(Coverage::Exhaustive);
+        assert_eq!(
+            vec![
+                Violation::UnreachableLimit {
+                    index: 0,
+                    limit: pos!(6),
+                    reached: nat!(4)
+                },
+                Violation::Oversized {
+                    index: 1,
+                    size: pos!(30),
+                    span: P10
+                },
+            ],
+            report.violations
+        );
+        assert_eq!(
+            "factor 0 never reaches its limit P(6), only N(4)",
+            report.violations[0].to_string()
+        );
+    }
+
+    #[test]
+    fn truncated_filter() {
+        let depth = |period| {
+            SimpleFilter::<Rep, _, 3>::new(
+                Period::new(pos!(period)),
+                [
+                    CycleKind::simple(pos!(5844)),
+                    CycleKind::simple(pos!(2922)),
+                    CycleKind::limited(pos!(365), pos!(7)),
+                ],
+            )
+        };
+        assert!(depth(93502).validate(Coverage::Exhaustive).is_valid());
+        let report = depth(93501).validate(Coverage::Exhaustive);
+        assert_eq!(
+            vec![Violation::TruncatedLast {
+                index: 2,
+                size: pos!(365),
+                last: nat!(364)
+            }],
+            report.violations
+        );
+        assert_eq!(
+            "factor 2 ends the period on a last cycle of N(364), short of its size P(365)",
+            report.violations[0].to_string()
+        );
+    }
+
+    #[test]
+    fn narrow_filter() {
+        let narrow = SimpleFilter::<WideningPeriodRepresentation<u8, i64>, _, 3>::new(
+            Period::new(pos!(93502)),
+            [
+                CycleKind::simple(pos!(5844)),
+                CycleKind::simple(pos!(2922)),
+                CycleKind::limited(pos!(365), pos!(7)),
+            ],
+        );
+        let report = narrow.validate(Coverage::Exhaustive);
+        assert_eq!(
+            vec![Violation::DigitOverflow {
+                index: 3,
+                digit: nat!(366)
+            }],
+            report.violations
+        );
+        assert_eq!(0, report.checked);
+
+        let narrow = SimpleMixed::<WideningPeriodRepresentation<u8, i64>, _, 2>::from_simple_factors([P4, pos!(257)]);
+        assert_eq!(
+            vec![Violation::DigitOverflow {
+                index: 1,
+                digit: nat!(256)
+            }],
+            narrow.validate(Coverage::Exhaustive).violations
+        );
+    }
+
+    #[test]
+    fn period_overflow() {
+        // The place values fit in i8, but not the product of all the sizes
+        let mixed = SimpleMixed::<IdentityPeriodRepresentation<i8>, _, 2>::new(
+            Period::new(pos!(8i8)),
+            [SimpleCycle::new(pos!(8i8)), SimpleCycle::new(pos!(16i8))],
+        );
+        let report = mixed.validate(Coverage::Exhaustive);
+        assert_eq!(Violation::PeriodOverflow { period: pos!(8i8) }, report.violations[0]);
+        assert_eq!(
+            "period P(8) is not the product of factor sizes, which overflows",
+            report.violations[0].to_string()
+        );
+    }
+}
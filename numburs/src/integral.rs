@@ -1,22 +1,33 @@
-use core::ops::{Add, Div, Mul, Rem};
+use core::{
+    cmp::max,
+    ops::{Add, Div, Mul, Rem, Sub},
+    str::FromStr,
+};
 use std::fmt::Display;
 
 use derive_more::{From, Neg};
-use serde::Serialize;
+use serde::{de, Deserialize, Deserializer, Serialize};
 
-use super::representation::{ConstrainedRep, HasOne, HasZero, LowerBoundedRep, NumBase, One, Zero};
+use super::representation::{
+    parse_number, sealed::Sealed, unexpected_integer, ConstrainedRep, ConstraintError, HasOne, HasZero,
+    LowerBoundedRep, NumBase, One, ParseError, Zero,
+};
 use crate::{has_one, has_zero};
 
-#[derive(Debug, From, PartialEq, PartialOrd, Eq, Ord, Clone, Copy, Serialize, Neg)]
+#[derive(Debug, From, PartialEq, PartialOrd, Eq, Ord, Clone, Copy, Serialize, Deserialize, Neg)]
+#[serde(transparent)]
 pub struct Integer<N: NumBase>(N);
 
-#[derive(Debug, From, PartialEq, PartialOrd, Eq, Ord, Clone, Copy, Serialize)]
+#[derive(Debug, PartialEq, PartialOrd, Eq, Ord, Clone, Copy, Serialize)]
+#[serde(transparent)]
 pub struct Natural<N: NumBase>(N);
 
-#[derive(Debug, From, PartialEq, PartialOrd, Eq, Ord, Clone, Copy, Serialize)]
+#[derive(Debug, PartialEq, PartialOrd, Eq, Ord, Clone, Copy, Serialize)]
+#[serde(transparent)]
 pub struct Positive<N: NumBase>(N);
 
-#[derive(Debug, From, PartialEq, PartialOrd, Eq, Ord, Clone, Copy, Serialize)]
+#[derive(Debug, PartialEq, PartialOrd, Eq, Ord, Clone, Copy, Serialize)]
+#[serde(transparent)]
 pub struct Many<N: NumBase>(N);
 
 impl<N: NumBase> Integer<N> {
@@ -25,6 +36,8 @@ impl<N: NumBase> Integer<N> {
     }
 }
 
+impl<N: NumBase> Sealed for Integer<N> {}
+
 impl<N: NumBase> ConstrainedRep<N> for Integer<N> {
     fn can_rep(_: &N) -> bool {
         true
@@ -33,6 +46,10 @@ impl<N: NumBase> ConstrainedRep<N> for Integer<N> {
     fn out(&self) -> N {
         self.0
     }
+
+    fn new_unchecked(x: N) -> Self {
+        Self(x)
+    }
 }
 
 impl<N: NumBase> LowerBoundedRep<N> for Natural<N> {
@@ -41,6 +58,8 @@ impl<N: NumBase> LowerBoundedRep<N> for Natural<N> {
     }
 }
 
+impl<N: NumBase> Sealed for Natural<N> {}
+
 impl<N: NumBase> ConstrainedRep<N> for Natural<N> {
     fn can_rep(x: &N) -> bool {
         Self::under_bottom() <= *x
@@ -49,6 +68,10 @@ impl<N: NumBase> ConstrainedRep<N> for Natural<N> {
     fn out(&self) -> N {
         self.0
     }
+
+    fn new_unchecked(x: N) -> Self {
+        Self(x)
+    }
 }
 
 impl<N: NumBase> LowerBoundedRep<N> for Positive<N> {
@@ -57,6 +80,8 @@ impl<N: NumBase> LowerBoundedRep<N> for Positive<N> {
     }
 }
 
+impl<N: NumBase> Sealed for Positive<N> {}
+
 impl<N: NumBase> ConstrainedRep<N> for Positive<N> {
     fn can_rep(x: &N) -> bool {
         Self::under_bottom() <= *x
@@ -65,8 +90,14 @@ impl<N: NumBase> ConstrainedRep<N> for Positive<N> {
     fn out(&self) -> N {
         self.0
     }
+
+    fn new_unchecked(x: N) -> Self {
+        Self(x)
+    }
 }
 
+impl<N: NumBase> Sealed for Many<N> {}
+
 impl<N: NumBase> ConstrainedRep<N> for Many<N> {
     fn can_rep(x: &N) -> bool {
         *x > N::ONE
@@ -75,6 +106,97 @@ impl<N: NumBase> ConstrainedRep<N> for Many<N> {
     fn out(&self) -> N {
         self.0
     }
+
+    fn new_unchecked(x: N) -> Self {
+        Self(x)
+    }
+}
+
+// Overflow-aware forms of the typed operators. Checked and saturated results
+// keep the output's constraint; a wrapped result may leave it, so it comes
+// back as an Integer alongside the overflow flag.
+pub trait ConstrainedAdd<N: NumBase, Rhs>: Add<Rhs> {
+    fn checked_add(self, rhs: Rhs) -> Option<Self::Output>;
+    fn saturating_add(self, rhs: Rhs) -> Self::Output;
+    fn overflowing_add(self, rhs: Rhs) -> (Integer<N>, bool);
+}
+
+pub trait ConstrainedMul<N: NumBase, Rhs>: Mul<Rhs> {
+    fn checked_mul(self, rhs: Rhs) -> Option<Self::Output>;
+    fn saturating_mul(self, rhs: Rhs) -> Self::Output;
+    fn overflowing_mul(self, rhs: Rhs) -> (Integer<N>, bool);
+}
+
+// A difference can fall below any lower bound, so the plain operator gives an
+// Integer. The checked and saturating forms stay within Bounded: Natural when
+// both sides are at least zero, Integer otherwise.
+pub trait ConstrainedSub<N: NumBase, Rhs>: Sub<Rhs, Output = Integer<N>> {
+    type Bounded;
+
+    fn checked_sub(self, rhs: Rhs) -> Option<Self::Bounded>;
+    fn saturating_sub(self, rhs: Rhs) -> Self::Bounded;
+    fn overflowing_sub(self, rhs: Rhs) -> (Integer<N>, bool);
+}
+
+#[cfg(feature = "panic-on-overflow")]
+fn raw_add<N: NumBase>(x: N, y: N) -> N {
+    x.checked_add(&y).expect("attempt to add with overflow")
+}
+
+#[cfg(not(feature = "panic-on-overflow"))]
+fn raw_add<N: NumBase>(x: N, y: N) -> N {
+    x + y
+}
+
+#[cfg(feature = "panic-on-overflow")]
+fn raw_sub<N: NumBase>(x: N, y: N) -> N {
+    x.checked_sub(&y).expect("attempt to subtract with overflow")
+}
+
+#[cfg(not(feature = "panic-on-overflow"))]
+fn raw_sub<N: NumBase>(x: N, y: N) -> N {
+    x - y
+}
+
+#[cfg(feature = "panic-on-overflow")]
+fn raw_mul<N: NumBase>(x: N, y: N) -> N {
+    x.checked_mul(&y).expect("attempt to multiply with overflow")
+}
+
+#[cfg(not(feature = "panic-on-overflow"))]
+fn raw_mul<N: NumBase>(x: N, y: N) -> N {
+    x * y
+}
+
+// Per primitive, since a generic TryFrom<N> overlaps core's blanket impl
+macro_rules! checked_from {
+    ($x:ident, $error:ident, $($t:ty),+) => {
+        $(
+            impl TryFrom<$t> for $x<$t> {
+                type Error = ConstraintError;
+
+                fn try_from(x: $t) -> Result<Self, Self::Error> {
+                    Self::rep(x).ok_or(ConstraintError::$error)
+                }
+            }
+        )+
+    };
+    ($($x:ident: $error:ident),+) => {
+        $(
+            checked_from!($x, $error, i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+        )+
+    };
+}
+
+macro_rules! checked_deserialize {
+    ($x:ident, $expected:expr) => {
+        impl<'de, N: NumBase + Deserialize<'de>> Deserialize<'de> for $x<N> {
+            fn deserialize<D: Deserializer<'de>>(d: D) -> Result<Self, D::Error> {
+                let x = N::deserialize(d)?;
+                Self::rep(x).ok_or_else(|| de::Error::invalid_value(unexpected_integer(x), &$expected))
+            }
+        }
+    };
 }
 
 macro_rules! does_map {
@@ -103,7 +225,22 @@ macro_rules! constrained_add {
             type Output = $z<N>;
 
             fn add(self, rhs: $y<N>) -> Self::Output {
-                $z(self.0 + rhs.0)
+                $z(raw_add(self.0, rhs.0))
+            }
+        }
+
+        impl<N: NumBase> ConstrainedAdd<N, $y<N>> for $x<N> {
+            fn checked_add(self, rhs: $y<N>) -> Option<$z<N>> {
+                self.0.checked_add(&rhs.0).map($z)
+            }
+
+            fn saturating_add(self, rhs: $y<N>) -> $z<N> {
+                $z(self.0.saturating_add(rhs.0))
+            }
+
+            fn overflowing_add(self, rhs: $y<N>) -> (Integer<N>, bool) {
+                let (value, overflow) = self.0.overflowing_add(&rhs.0);
+                (Integer(value), overflow)
             }
         }
     };
@@ -122,7 +259,7 @@ macro_rules! constrained_right_inc {
             type Output = $z<N>;
 
             fn add(self, _rhs: One) -> Self::Output {
-                $z(self.0 + N::ONE)
+                $z(raw_add(self.0, N::ONE))
             }
         }
     };
@@ -134,7 +271,7 @@ macro_rules! constrained_left_inc {
             type Output = $z<N>;
 
             fn add(self, rhs: $y<N>) -> Self::Output {
-                $z(N::ONE + rhs.0)
+                $z(raw_add(N::ONE, rhs.0))
             }
         }
     };
@@ -147,13 +284,80 @@ macro_rules! constrained_inc {
     };
 }
 
+macro_rules! constrained_sub {
+    ($x:ident, $y:ident, Integer) => {
+        constrained_sub!(@sub $x, $y);
+
+        impl<N: NumBase> ConstrainedSub<N, $y<N>> for $x<N> {
+            type Bounded = Integer<N>;
+
+            fn checked_sub(self, rhs: $y<N>) -> Option<Integer<N>> {
+                self.0.checked_sub(&rhs.0).map(Integer)
+            }
+
+            fn saturating_sub(self, rhs: $y<N>) -> Integer<N> {
+                Integer(self.0.saturating_sub(rhs.0))
+            }
+
+            constrained_sub!(@overflowing $y);
+        }
+    };
+    ($x:ident, $y:ident, Natural) => {
+        constrained_sub!(@sub $x, $y);
+
+        impl<N: NumBase> ConstrainedSub<N, $y<N>> for $x<N> {
+            type Bounded = Natural<N>;
+
+            fn checked_sub(self, rhs: $y<N>) -> Option<Natural<N>> {
+                self.0.checked_sub(&rhs.0).filter(|d| *d >= N::ZERO).map(Natural)
+            }
+
+            fn saturating_sub(self, rhs: $y<N>) -> Natural<N> {
+                Natural(max(self.0.saturating_sub(rhs.0), N::ZERO))
+            }
+
+            constrained_sub!(@overflowing $y);
+        }
+    };
+    (@sub $x:ident, $y:ident) => {
+        impl<N: NumBase> Sub<$y<N>> for $x<N> {
+            type Output = Integer<N>;
+
+            fn sub(self, rhs: $y<N>) -> Self::Output {
+                Integer(raw_sub(self.0, rhs.0))
+            }
+        }
+    };
+    (@overflowing $y:ident) => {
+        fn overflowing_sub(self, rhs: $y<N>) -> (Integer<N>, bool) {
+            let (value, overflow) = self.0.overflowing_sub(&rhs.0);
+            (Integer(value), overflow)
+        }
+    };
+}
+
 macro_rules! constrained_mul {
     ($x:ident, $y:ident, $z:ident) => {
         impl<N: NumBase> Mul<$y<N>> for $x<N> {
             type Output = $z<N>;
 
             fn mul(self, rhs: $y<N>) -> Self::Output {
-                $z(self.0 * rhs.0)
+                $z(raw_mul(self.0, rhs.0))
+            }
+        }
+
+        impl<N: NumBase> ConstrainedMul<N, $y<N>> for $x<N> {
+            fn checked_mul(self, rhs: $y<N>) -> Option<$z<N>> {
+                self.0.checked_mul(&rhs.0).map($z)
+            }
+
+            fn saturating_mul(self, rhs: $y<N>) -> $z<N> {
+                $z(self.0.saturating_mul(&rhs.0))
+            }
+
+            fn overflowing_mul(self, rhs: $y<N>) -> (Integer<N>, bool) {
+                let (value, overflow) = self.0.overflowing_mul(&rhs.0);
+                (Integer(value), overflow)
             }
         }
     };
@@ -166,6 +370,8 @@ macro_rules! constrained_mul_sym {
     };
 }
 
+// A natural divided by a positive can neither overflow nor divide by zero, so
+// division has no checked forms
 macro_rules! constrained_div {
     ($x:ident, $y:ident, $z:ident) => {
         impl<N: NumBase> Div<$y<N>> for $x<N> {
@@ -186,6 +392,95 @@ macro_rules! constrained_div {
     };
 }
 
+macro_rules! const_ops {
+    // Plain operators would wrap silently at runtime in release builds
+    (@checked $e:expr, $op:literal) => {
+        match $e {
+            Some(value) => value,
+            None => panic!(concat!("Overflow in constant ", $op)),
+        }
+    };
+    ($($t:ty),+) => {
+        $(
+            impl Integer<$t> {
+                pub const fn new_const(n: $t) -> Self {
+                    Integer(n)
+                }
+
+                pub const fn out_const(self) -> $t {
+                    self.0
+                }
+
+                pub const fn const_add(self, rhs: Self) -> Self {
+                    Integer(const_ops!(@checked self.0.checked_add(rhs.0), "addition"))
+                }
+
+                pub const fn const_mul(self, rhs: Self) -> Self {
+                    Integer(const_ops!(@checked self.0.checked_mul(rhs.0), "multiplication"))
+                }
+            }
+
+            #[allow(unused_comparisons)]
+            impl Natural<$t> {
+                pub const fn new_const(n: $t) -> Self {
+                    assert!(n >= 0, "Natural must not be negative");
+                    Natural(n)
+                }
+
+                pub const fn out_const(self) -> $t {
+                    self.0
+                }
+
+                pub const fn const_add(self, rhs: Self) -> Self {
+                    Natural(const_ops!(@checked self.0.checked_add(rhs.0), "addition"))
+                }
+
+                pub const fn const_mul(self, rhs: Self) -> Self {
+                    Natural(const_ops!(@checked self.0.checked_mul(rhs.0), "multiplication"))
+                }
+            }
+
+            impl Positive<$t> {
+                pub const fn new_const(n: $t) -> Self {
+                    assert!(n >= 1, "Positive must be at least 1");
+                    Positive(n)
+                }
+
+                pub const fn out_const(self) -> $t {
+                    self.0
+                }
+
+                pub const fn const_add(self, rhs: Self) -> Many<$t> {
+                    Many(const_ops!(@checked self.0.checked_add(rhs.0), "addition"))
+                }
+
+                pub const fn const_mul(self, rhs: Self) -> Self {
+                    Positive(const_ops!(@checked self.0.checked_mul(rhs.0), "multiplication"))
+                }
+            }
+
+            impl Many<$t> {
+                pub const fn new_const(n: $t) -> Self {
+                    assert!(n > 1, "Many must be at least 2");
+                    Many(n)
+                }
+
+                pub const fn out_const(self) -> $t {
+                    self.0
+                }
+
+                pub const fn const_add(self, rhs: Self) -> Self {
+                    Many(const_ops!(@checked self.0.checked_add(rhs.0), "addition"))
+                }
+
+                pub const fn const_mul(self, rhs: Self) -> Self {
+                    Many(const_ops!(@checked self.0.checked_mul(rhs.0), "multiplication"))
+                }
+            }
+        )+
+    };
+}
+
 macro_rules! display_with {
     ($x:ident, $y:expr) => {
         impl<N: NumBase + Display> Display for $x<N> {
@@ -196,11 +491,37 @@ macro_rules! display_with {
     };
 }
 
+// Reads what display_with writes, or the bare number
+macro_rules! parsed_with {
+    ($x:ident, $y:expr, $error:ident) => {
+        impl<N: NumBase> FromStr for $x<N> {
+            type Err = ParseError;
+
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                let x = parse_number(s, Some($y))?;
+                Self::rep(x).ok_or(ParseError::Constraint(ConstraintError::$error))
+            }
+        }
+    };
+}
+
+impl<N: NumBase> FromStr for Integer<N> {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse_number(s, Some("I")).map(Integer)
+    }
+}
+
 display_with!(Integer, "I");
 display_with!(Natural, "N");
 display_with!(Positive, "P");
 display_with!(Many, "M");
 
+parsed_with!(Natural, "N", Negative);
+parsed_with!(Positive, "P", NotPositive);
+parsed_with!(Many, "M", NotMany);
+
 has_zero!(Integer);
 has_zero!(Natural);
 has_one!(Integer);
@@ -209,6 +530,12 @@ has_one!(Positive);
 
 does_map!(Integer);
 
+checked_from!(Natural: Negative, Positive: NotPositive, Many: NotMany);
+
+checked_deserialize!(Natural, "a natural number");
+checked_deserialize!(Positive, "a positive number");
+checked_deserialize!(Many, "a number of at least 2");
+
 embedding!(Many, Positive);
 embedding!(Many, Natural);
 embedding!(Many, Integer);
@@ -231,6 +558,23 @@ constrained_inc!(Natural, Positive);
 constrained_inc!(Positive, Many);
 constrained_inc!(Many, Many);
 
+constrained_sub!(Integer, Integer, Integer);
+constrained_sub!(Integer, Natural, Integer);
+constrained_sub!(Integer, Positive, Integer);
+constrained_sub!(Integer, Many, Integer);
+constrained_sub!(Natural, Integer, Integer);
+constrained_sub!(Positive, Integer, Integer);
+constrained_sub!(Many, Integer, Integer);
+constrained_sub!(Natural, Natural, Natural);
+constrained_sub!(Natural, Positive, Natural);
+constrained_sub!(Natural, Many, Natural);
+constrained_sub!(Positive, Natural, Natural);
+constrained_sub!(Positive, Positive, Natural);
+constrained_sub!(Positive, Many, Natural);
+constrained_sub!(Many, Natural, Natural);
+constrained_sub!(Many, Positive, Natural);
+constrained_sub!(Many, Many, Natural);
+
 constrained_mul!(Integer, Integer, Integer);
 constrained_mul_sym!(Integer, Natural, Integer);
 constrained_mul_sym!(Integer, Positive, Integer);
@@ -245,10 +589,17 @@ constrained_mul!(Many, Many, Many);
 constrained_div!(Natural, Positive, Natural);
 constrained_div!(Natural, Many, Natural);
 
+const_ops!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+
 impl<N: NumBase> Positive<N> {
+    // Defined for every x, including those within a divisor of N's minimum
     pub fn euclid(self, x: Integer<N>) -> (Integer<N>, Natural<N>) {
-        let q = (x.0 - (if x.0 < N::ZERO { self.0 - N::ONE } else { N::ZERO })) / self.0;
-        (Integer(q), Natural(x.0 - q * self.0))
+        let (q, r) = (x.0 / self.0, x.0 % self.0);
+        if r < N::ZERO {
+            (Integer(q - N::ONE), Natural(r + self.0))
+        } else {
+            (Integer(q), Natural(r))
+        }
     }
 }
 
@@ -259,30 +610,64 @@ macro_rules! int {
     };
 }
 
+// Literals are checked when compiled, at the type of the use site, so
+// `nat!(-3)` does not build, and other expressions when evaluated.
+// `pos!(const i64: 7)` names the representation so that it can initialize a
+// const.
 #[macro_export]
 macro_rules! nat {
+    (const $t:ty: $x:expr) => {
+        $crate::Natural::<$t>::new_const($x)
+    };
+    ($x:literal) => {{
+        #[allow(unused_comparisons)]
+        let value = const {
+            let value = $x;
+            assert!(value >= 0, "nat! needs a value of at least 0");
+            value
+        };
+        <$crate::Natural<_> as $crate::ConstrainedRep<_>>::new_unchecked(value)
+    }};
     ($x:expr) => {
-        Natural::at_least($x)
+        <$crate::Natural<_> as $crate::ConstrainedRep<_>>::rep($x).expect("nat! needs a value of at least 0")
     };
 }
 
 #[macro_export]
 macro_rules! pos {
+    (const $t:ty: $x:expr) => {
+        $crate::Positive::<$t>::new_const($x)
+    };
+    ($x:literal) => {{
+        let value = const {
+            let value = $x;
+            assert!(value >= 1, "pos! needs a value of at least 1");
+            value
+        };
+        <$crate::Positive<_> as $crate::ConstrainedRep<_>>::new_unchecked(value)
+    }};
     ($x:expr) => {
-        Positive::at_least($x)
+        <$crate::Positive<_> as $crate::ConstrainedRep<_>>::rep($x).expect("pos! needs a value of at least 1")
+    };
+}
+
+// Declares constants that fail the build when out of range, as in
+// `constants!(pub Positive<i64>: WEEK = 7, MONTH = 30);`
+#[macro_export]
+macro_rules! constants {
+    ($vis:vis $type:ident<$t:ty>: $($name:ident = $x:expr),+ $(,)?) => {
+        $(
+            $vis const $name: $crate::$type<$t> = $crate::$type::<$t>::new_const($x);
+        )+
     };
 }
 
 pub mod testing {
     use paste::paste;
 
-    use super::{Integer, Many, Natural, Positive};
-
     macro_rules! generate_constants {
         ($p:ident, $type:ident, $($val:expr),+) => {
-            $(
-                paste!{ pub const [<$p $val>]: $type<i64> = $type($val); }
-            )+
+            paste! { constants!(pub $type<i64>: $([<$p $val>] = $val),+); }
         };
     }
 
@@ -294,7 +679,10 @@ pub mod testing {
 
 #[cfg(test)]
 mod tests {
-    use super::{testing::*, Integer, LowerBoundedRep, Natural, One, Positive, Zero};
+    use super::{
+        testing::*, ConstrainedAdd, ConstrainedMul, ConstrainedRep, ConstrainedSub, ConstraintError, Integer, Many,
+        Natural, One, ParseError, Positive, Zero,
+    };
 
     #[test]
     fn embedding() {
@@ -306,6 +694,69 @@ mod tests {
         assert_eq!(pos!(2), M2.into());
     }
 
+    #[test]
+    fn checked_construction() {
+        assert_eq!(Ok(N0), Natural::try_from(0));
+        assert_eq!(Ok(P3), Positive::try_from(3));
+        assert_eq!(Ok(M2), Many::try_from(2));
+        assert_eq!(Err(ConstraintError::Negative), Natural::try_from(-5));
+        assert_eq!(Err(ConstraintError::NotPositive), Positive::try_from(0));
+        assert_eq!(Err(ConstraintError::NotMany), Many::try_from(1u8));
+        assert_eq!(Ok(nat!(7u16)), 7u16.try_into());
+        assert_eq!("Positive must be at least 1", ConstraintError::NotPositive.to_string());
+        assert_eq!(P2, Positive::new_unchecked(2));
+    }
+
+    #[test]
+    fn deserialize() {
+        assert_eq!("5", serde_json::to_string(&P5).unwrap());
+        assert_eq!(I5, serde_json::from_str("5").unwrap());
+        assert_eq!(-I3, serde_json::from_str("-3").unwrap());
+        assert_eq!(N0, serde_json::from_str("0").unwrap());
+        assert_eq!(M2, serde_json::from_str("2").unwrap());
+        assert_eq!(
+            "invalid value: integer `-5`, expected a natural number",
+            serde_json::from_str::<Natural<i64>>("-5").unwrap_err().to_string()
+        );
+        assert_eq!(
+            "invalid value: integer `0`, expected a positive number",
+            serde_json::from_str::<Positive<u8>>("0").unwrap_err().to_string()
+        );
+        assert!(serde_json::from_str::<Many<i64>>("1").is_err());
+        assert!(serde_json::from_str::<Natural<u8>>("300").is_err());
+    }
+
+    #[test]
+    fn parse() {
+        assert_eq!(Ok(P5), "5".parse());
+        assert_eq!(Ok(P3), "P(3)".parse());
+        assert_eq!(Ok(N5), "N(5)".parse());
+        assert_eq!(Ok(-I4), "I(-4)".parse());
+        assert_eq!(Ok(M2), " 2 ".parse());
+        for value in [N0, N7, nat!(i64::MAX)] {
+            assert_eq!(Ok(value), value.to_string().parse());
+        }
+        assert_eq!(Ok(nat!(200u8)), "N(200)".parse());
+        assert_eq!(
+            Err(ParseError::Constraint(ConstraintError::Negative)),
+            "-5".parse::<Natural<i64>>()
+        );
+        assert_eq!(
+            Err(ParseError::Constraint(ConstraintError::NotPositive)),
+            "P(0)".parse::<Positive<i64>>()
+        );
+        assert_eq!(Err(ParseError::Syntax), "five".parse::<Integer<i64>>());
+        assert_eq!(Err(ParseError::Syntax), "P(3)".parse::<Natural<i64>>());
+        assert_eq!(Err(ParseError::OutOfRange), "N(300)".parse::<Natural<u8>>());
+        assert_eq!(Err(ParseError::OutOfRange), "-1".parse::<Natural<u8>>());
+        assert_eq!(Err(ParseError::Syntax), "N(+)".parse::<Natural<u8>>());
+        assert_eq!("number out of range", ParseError::OutOfRange.to_string());
+        assert_eq!(
+            "Many must be at least 2",
+            ParseError::Constraint(ConstraintError::NotMany).to_string()
+        );
+    }
+
     #[test]
     fn embedding_constants() {
         assert_eq!(I0, Zero.into());
@@ -325,6 +776,98 @@ mod tests {
         assert_eq!(P6, P2 * P3);
     }
 
+    #[test]
+    fn overflow_aware_ops() {
+        assert_eq!(Some(M5), P2.checked_add(P3));
+        assert_eq!(None, pos!(i64::MAX).checked_add(P1));
+        assert_eq!(Many(i64::MAX), pos!(i64::MAX).saturating_add(P1));
+        assert_eq!((int!(i64::MIN), true), pos!(i64::MAX).overflowing_add(P1));
+        assert_eq!((I5, false), N2.overflowing_add(N3));
+        assert_eq!(int!(i64::MIN), int!(i64::MIN + 1).saturating_add(-I2));
+
+        assert_eq!(Some(P6), P2.checked_mul(P3));
+        assert_eq!(None, pos!(200u8).checked_mul(pos!(2u8)));
+        assert_eq!(pos!(u8::MAX), pos!(200u8).saturating_mul(pos!(2u8)));
+        // The wrapped product of positives can be zero, outside Positive
+        assert_eq!((Integer::new(0u8), true), pos!(16u8).overflowing_mul(pos!(16u8)));
+        assert_eq!(int!(i64::MIN), int!(i64::MIN / 2).saturating_mul(M3));
+        assert_eq!(None, nat!(i64::MAX).checked_mul(M2));
+    }
+
+    #[test]
+    fn subtraction() {
+        assert_eq!(-I1, N2 - N3);
+        assert_eq!(I1, P3 - N2);
+        assert_eq!(-I5, -I2 - P3);
+        assert_eq!(I5, M2 - -I3);
+        assert_eq!(Some(N1), N3.checked_sub(N2));
+        assert_eq!(Some(N0), P3.checked_sub(P3));
+        assert_eq!(None, N2.checked_sub(N3));
+        assert_eq!(None, M2.checked_sub(P3));
+        assert_eq!(N0, N2.saturating_sub(M3));
+        assert_eq!(N4, M7.saturating_sub(P3));
+        assert_eq!(Some(-I1), I2.checked_sub(P3));
+        assert_eq!(None, int!(i64::MIN).checked_sub(N1));
+        assert_eq!(int!(i64::MIN), int!(i64::MIN).saturating_sub(N1));
+        assert_eq!(int!(i64::MAX), nat!(i64::MAX).saturating_sub(-I1));
+        assert_eq!((int!(i64::MAX), true), int!(i64::MIN).overflowing_sub(P1));
+        // Unsigned differences floor at zero rather than wrapping
+        assert_eq!(None, nat!(2u8).checked_sub(nat!(3u8)));
+        assert_eq!(nat!(0u8), nat!(2u8).saturating_sub(pos!(3u8)));
+    }
+
+    #[test]
+    #[cfg(any(debug_assertions, feature = "panic-on-overflow"))]
+    #[should_panic]
+    fn plain_ops_panic_on_overflow() {
+        let _ = pos!(i64::MAX) * M2;
+    }
+
+    #[test]
+    fn const_constructors() {
+        const SIZE: Positive<i64> = Positive::<i64>::new_const(2).const_mul(Positive::<i64>::new_const(3));
+        const COUNT: Natural<i64> = Natural::<i64>::new_const(2).const_add(Natural::<i64>::new_const(0));
+        assert_eq!(P6, SIZE);
+        assert_eq!(N2, COUNT);
+        assert_eq!(M5, P2.const_add(P3));
+        assert_eq!(I6, I2.const_mul(I3));
+    }
+
+    #[test]
+    #[should_panic(expected = "Overflow in constant multiplication")]
+    fn const_ops_overflow() {
+        let _ = Positive::<u8>::new_const(16).const_mul(Positive::<u8>::new_const(16));
+    }
+
+    #[test]
+    #[should_panic]
+    fn const_constructor_out_of_range() {
+        Positive::<i64>::new_const(0);
+    }
+
+    constants!(Many<u8>: PAIR = 2, DOZEN = 12);
+
+    #[test]
+    fn checked_literals() {
+        const WEEK: Positive<i64> = pos!(const i64: 7);
+        assert_eq!(pos!(7), WEEK);
+        assert_eq!(nat!(0u8), Natural::new_unchecked(0u8));
+        assert_eq!(Many::new_unchecked(24), PAIR.const_mul(DOZEN));
+        let k = 3;
+        assert_eq!(N3, nat!(k));
+        assert_eq!(P6, pos!(k * 2));
+        let wide: Positive<i64> = pos!(3_000_000_000);
+        assert_eq!(3_000_000_000, wide.out());
+        assert_eq!(nat!(4_000_000_000u32), Natural::new_unchecked(4_000_000_000u32));
+    }
+
+    #[test]
+    #[should_panic(expected = "nat! needs a value of at least 0")]
+    fn checked_expressions() {
+        let k: i64 = -3;
+        let _ = nat!(k);
+    }
+
     #[test]
     fn does_map() {
         let x: i32 = 5;
@@ -366,4 +909,12 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    fn euclid_extremes() {
+        assert_eq!((int!(i64::MIN / 7 - 1), nat!(6)), pos!(7).euclid(int!(i64::MIN)));
+        assert_eq!((int!(i64::MAX / 7), N0), pos!(7).euclid(int!(i64::MAX)));
+        assert_eq!((int!(i64::MIN), N0), pos!(1).euclid(int!(i64::MIN)));
+        assert_eq!((int!(-2), nat!(i64::MAX - 1)), pos!(i64::MAX).euclid(int!(i64::MIN)));
+    }
 }
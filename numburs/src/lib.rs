@@ -1,6 +1,8 @@
 pub mod integral;
 pub mod real;
 pub mod representation;
-pub use integral::{Integer, Many, Natural, Positive};
+pub use integral::{ConstrainedAdd, ConstrainedMul, ConstrainedSub, Integer, Many, Natural, Positive};
 pub use real::{Fractional, Real, WithFraction};
-pub use representation::{BoundedRepLeft, ConstrainedRep, FloatBase, HasOne, HasZero, LowerBoundedRep, NumBase};
+pub use representation::{
+    BoundedRepLeft, ConstrainedRep, ConstraintError, FloatBase, HasOne, HasZero, LowerBoundedRep, NumBase, ParseError,
+};
@@ -1,26 +1,57 @@
+use core::{
+    fmt::{self, Display},
+    str::FromStr,
+};
+
 use derive_more::{Add, AddAssign, Constructor, Div, From, Mul, Neg, Rem, Sub};
-use serde::Serialize;
+use serde::{
+    de::{self, Unexpected},
+    Deserialize, Deserializer, Serialize,
+};
 
 use super::{
     integral::Integer,
     representation::{FloatBase, NumBase},
 };
-use crate::ConstrainedRep;
+use crate::{
+    representation::{parse_number, sealed::Sealed, ParseError},
+    ConstrainedRep, ConstraintError,
+};
 
-#[derive(Debug, Mul, PartialEq, PartialOrd, Eq, Ord, Clone, Copy, From, Serialize)]
+#[derive(Debug, Mul, PartialEq, PartialOrd, Eq, Ord, Clone, Copy, Serialize)]
+#[serde(transparent)]
 pub struct Fractional<F: FloatBase>(F);
 
 #[derive(
-    Debug, Add, AddAssign, Sub, Mul, Div, Neg, Rem, From, PartialEq, PartialOrd, Eq, Ord, Clone, Copy, Serialize,
+    Debug,
+    Add,
+    AddAssign,
+    Sub,
+    Mul,
+    Div,
+    Neg,
+    Rem,
+    From,
+    PartialEq,
+    PartialOrd,
+    Eq,
+    Ord,
+    Clone,
+    Copy,
+    Serialize,
+    Deserialize,
 )]
+#[serde(transparent)]
 pub struct Real<F: FloatBase>(F);
 
-#[derive(Debug, Constructor, PartialEq, Serialize, Clone)]
+#[derive(Debug, Constructor, PartialEq, Serialize, Deserialize, Clone)]
 pub struct WithFraction<X, F: FloatBase> {
     pub whole: X,
     pub fraction: Fractional<F>,
 }
 
+impl<F: FloatBase> Sealed for Fractional<F> {}
+
 impl<F: FloatBase> ConstrainedRep<F> for Fractional<F> {
     fn can_rep(x: &F) -> bool {
         F::ZERO <= *x && *x < F::ONE
@@ -29,8 +60,14 @@ impl<F: FloatBase> ConstrainedRep<F> for Fractional<F> {
     fn out(&self) -> F {
         self.0
     }
+
+    fn new_unchecked(x: F) -> Self {
+        Self(x)
+    }
 }
 
+impl<F: FloatBase> Sealed for Real<F> {}
+
 impl<F: FloatBase> ConstrainedRep<F> for Real<F> {
     fn can_rep(_: &F) -> bool {
         true
@@ -39,6 +76,67 @@ impl<F: FloatBase> ConstrainedRep<F> for Real<F> {
     fn out(&self) -> F {
         self.0
     }
+
+    fn new_unchecked(x: F) -> Self {
+        Self(x)
+    }
+}
+
+macro_rules! checked_from {
+    ($($t:ty),+) => {
+        $(
+            impl TryFrom<$t> for Fractional<$t> {
+                type Error = ConstraintError;
+
+                fn try_from(x: $t) -> Result<Self, Self::Error> {
+                    Self::rep(x).ok_or(ConstraintError::NotFractional)
+                }
+            }
+        )+
+    };
+}
+
+checked_from!(f32, f64);
+
+impl<'de, F: FloatBase + Deserialize<'de>> Deserialize<'de> for Fractional<F> {
+    fn deserialize<D: Deserializer<'de>>(d: D) -> Result<Self, D::Error> {
+        let x = F::deserialize(d)?;
+        let unexpected = Unexpected::Float(x.to_f64().unwrap_or(f64::NAN));
+        Self::rep(x).ok_or_else(|| de::Error::invalid_value(unexpected, &"a fraction at least 0 and below 1"))
+    }
+}
+
+impl<F: FloatBase> FromStr for Fractional<F> {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let x = parse_number(s, None)?;
+        Self::rep(x).ok_or(ParseError::Constraint(ConstraintError::NotFractional))
+    }
+}
+
+impl<F: FloatBase> FromStr for Real<F> {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse_number(s, None).map(Real)
+    }
+}
+
+// Written as the whole part plus the fraction, as in "I(-2) + 0.5"
+impl<X: Display, F: FloatBase + Display> Display for WithFraction<X, F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} + {}", self.whole, self.fraction.0)
+    }
+}
+
+impl<X: FromStr<Err = ParseError>, F: FloatBase> FromStr for WithFraction<X, F> {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (whole, fraction) = s.split_once(" + ").ok_or(ParseError::Syntax)?;
+        Ok(Self::new(whole.parse()?, fraction.parse()?))
+    }
 }
 
 impl<F: FloatBase> Real<F> {
@@ -85,13 +183,63 @@ mod tests {
     use assert_approx_eq::assert_approx_eq;
 
     use super::testing::*;
-    use crate::{integral::testing::*, WithFraction};
+    use crate::{integral::testing::*, ConstraintError, Fractional, Integer, ParseError, Real, WithFraction};
 
     fn assert_with_fraction_eq<X: Eq + Debug>(l: WithFraction<X, f32>, r: WithFraction<X, f32>) {
         assert_eq!(l.whole, r.whole);
         assert_approx_eq!(l.fraction.0, r.fraction.0);
     }
 
+    #[test]
+    fn checked_fractional() {
+        assert_eq!(Ok(F_5), Fractional::try_from(0.5));
+        assert_eq!(Err(ConstraintError::NotFractional), Fractional::try_from(1.0f32));
+        assert_eq!(Err(ConstraintError::NotFractional), Fractional::try_from(-0.25f64));
+        assert!(Fractional::try_from(f64::NAN).is_err());
+    }
+
+    #[test]
+    fn deserialize() {
+        assert_eq!(F_5, serde_json::from_str("0.5").unwrap());
+        assert_eq!(-R2_5, serde_json::from_str("-2.5").unwrap());
+        assert_eq!(
+            "invalid value: floating point `1.0`, expected a fraction at least 0 and below 1",
+            serde_json::from_str::<Fractional<f32>>("1.0").unwrap_err().to_string()
+        );
+        let whole = WithFraction::new(-I2, F_5);
+        let json = serde_json::to_string(&whole).unwrap();
+        assert_eq!(r#"{"whole":-2,"fraction":0.5}"#, json);
+        assert_eq!(whole, serde_json::from_str(&json).unwrap());
+        assert!(serde_json::from_str::<WithFraction<Integer<i64>, f32>>(r#"{"whole":1,"fraction":1.5}"#).is_err());
+    }
+
+    #[test]
+    fn parse() {
+        assert_eq!(Ok(F_5), "0.5".parse());
+        assert_eq!(Ok(-R2_5), "-2.5".parse());
+        assert_eq!(
+            Err(ParseError::Constraint(ConstraintError::NotFractional)),
+            "1.25".parse::<Fractional<f64>>()
+        );
+        assert_eq!(Err(ParseError::Syntax), "half".parse::<Real<f32>>());
+    }
+
+    #[test]
+    fn parse_with_fraction() {
+        let whole = WithFraction::new(-I2, F_5);
+        assert_eq!("I(-2) + 0.5", whole.to_string());
+        assert_eq!(Ok(whole.clone()), whole.to_string().parse());
+        assert_eq!(Ok(whole), "-2 + 0.5".parse());
+        assert_eq!(
+            Err(ParseError::Constraint(ConstraintError::NotFractional)),
+            "I(1) + 1.5".parse::<WithFraction<Integer<i64>, f32>>()
+        );
+        assert_eq!(
+            Err(ParseError::Syntax),
+            "I(1)".parse::<WithFraction<Integer<i64>, f32>>()
+        );
+    }
+
     #[test]
     fn test_real_fractionalize_positive() {
         assert_with_fraction_eq(WithFraction::new(I0, F_0), R0_0.fractionalize());
@@ -1,14 +1,25 @@
-use core::cmp::max;
+use core::{cmp::max, fmt};
 
 use derive_more::From;
 use num_traits::{
     clamp,
     identities::{ConstOne, ConstZero},
-    Float, PrimInt,
+    ops::{
+        overflowing::{OverflowingAdd, OverflowingMul, OverflowingSub},
+        saturating::SaturatingMul,
+    },
+    Float, Num, PrimInt,
 };
+use serde::de::Unexpected;
 
-pub trait NumBase: PrimInt + ConstOne + ConstZero {}
-impl<N: PrimInt + ConstZero + ConstOne> NumBase for N {}
+pub trait NumBase:
+    PrimInt + ConstOne + ConstZero + OverflowingAdd + OverflowingSub + OverflowingMul + SaturatingMul
+{
+}
+impl<N: PrimInt + ConstZero + ConstOne + OverflowingAdd + OverflowingSub + OverflowingMul + SaturatingMul> NumBase
+    for N
+{
+}
 
 pub trait FloatBase: Float + ConstOne + ConstZero {}
 impl<F: Float + ConstZero + ConstOne> FloatBase for F {}
@@ -19,37 +30,118 @@ pub struct Zero;
 #[derive(Debug, From, PartialEq, PartialOrd, Eq, Ord, Clone, Copy)]
 pub struct One;
 
-pub trait ConstrainedRep<N: Copy>: From<N> {
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum ConstraintError {
+    Negative,
+    NotPositive,
+    NotMany,
+    NotFractional,
+}
+
+impl fmt::Display for ConstraintError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ConstraintError::Negative => write!(f, "Natural must not be negative"),
+            ConstraintError::NotPositive => write!(f, "Positive must be at least 1"),
+            ConstraintError::NotMany => write!(f, "Many must be at least 2"),
+            ConstraintError::NotFractional => write!(f, "Fractional must be at least 0 and below 1"),
+        }
+    }
+}
+
+impl std::error::Error for ConstraintError {}
+
+// How a rejected value shows up in a deserialization error
+pub(crate) fn unexpected_integer<N: NumBase>(x: N) -> Unexpected<'static> {
+    match (x.to_i64(), x.to_u64()) {
+        (Some(v), _) => Unexpected::Signed(v),
+        (None, Some(v)) => Unexpected::Unsigned(v),
+        (None, None) => Unexpected::Other("integer out of range"),
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum ParseError {
+    Syntax,
+    // An integer too large or too small for the type, as in "300" for u8
+    OutOfRange,
+    Constraint(ConstraintError),
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseError::Syntax => write!(f, "not a number"),
+            ParseError::OutOfRange => write!(f, "number out of range"),
+            ParseError::Constraint(error) => error.fmt(f),
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+// Reads a plain number, or one wrapped in its Display tag as in "N(5)"
+pub(crate) fn parse_number<N: Num>(s: &str, tag: Option<&str>) -> Result<N, ParseError> {
+    let s = s.trim();
+    let inner = tag
+        .and_then(|tag| s.strip_prefix(tag))
+        .and_then(|rest| rest.strip_prefix('('))
+        .and_then(|rest| rest.strip_suffix(')'))
+        .unwrap_or(s);
+    N::from_str_radix(inner, 10).map_err(|_| {
+        // Digits that only fail to fit the type are not a syntax error
+        let digits = inner.strip_prefix(['+', '-']).unwrap_or(inner);
+        if !digits.is_empty() && digits.bytes().all(|b| b.is_ascii_digit()) {
+            ParseError::OutOfRange
+        } else {
+            ParseError::Syntax
+        }
+    })
+}
+
+// Only the types of this crate are constrained representations, so that no
+// other type can be handed to code relying on the constraint holding
+pub(crate) mod sealed {
+    pub trait Sealed {}
+}
+
+pub trait ConstrainedRep<N: Copy>: Sized + sealed::Sealed {
     fn can_rep(x: &N) -> bool;
     fn out(&self) -> N;
 
+    // Skips can_rep, so the caller must know that x is representable. Hidden
+    // since it is only for the crates of this workspace and the `nat!` and
+    // `pos!` macros; everything else should go through `rep`.
+    #[doc(hidden)]
+    fn new_unchecked(x: N) -> Self;
+
     fn rep(x: N) -> Option<Self> {
-        Some(x).filter(Self::can_rep).map(Self::from)
+        Some(x).filter(Self::can_rep).map(Self::new_unchecked)
     }
 }
 
-pub trait LowerBoundedRep<N: Ord>: From<N> {
+pub trait LowerBoundedRep<N: Ord + Copy>: ConstrainedRep<N> {
     fn under_bottom() -> N;
 
     fn bottom() -> Self {
-        Self::from(Self::under_bottom())
+        Self::new_unchecked(Self::under_bottom())
     }
 
     fn at_least(x: N) -> Self {
-        Self::from(max(x, Self::under_bottom()))
+        Self::new_unchecked(max(x, Self::under_bottom()))
     }
 }
 
-pub trait BoundedRepLeft<N: Ord>: From<N> {
+pub trait BoundedRepLeft<N: Ord + Copy>: ConstrainedRep<N> {
     fn under_bottom() -> N;
     fn under_top() -> N;
 
     fn bottom() -> Self {
-        Self::from(Self::under_bottom())
+        Self::new_unchecked(Self::under_bottom())
     }
 
     fn clamped(x: N) -> Self {
-        Self::from(clamp(x, Self::under_bottom(), Self::under_top()))
+        Self::new_unchecked(clamp(x, Self::under_bottom(), Self::under_top()))
     }
 }
 
@@ -2,6 +2,6 @@
 // Size Aliases
 ////////////////////////////////////////////////////////////////////////////////
 
-// pub type Q = u8;
+pub type Q = u8;
 pub type I = i64;
 pub type R = f64;
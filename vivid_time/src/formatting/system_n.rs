@@ -8,7 +8,7 @@ use super::{
     types::Formatter,
 };
 use crate::{
-    base::{I, R},
+    base::{I, Q, R},
     system_n::{
         calendar::Calendar,
         clock::Clock,
@@ -51,7 +51,7 @@ impl Formatter<Depth> for DepthFormatter<'_> {
 }
 
 pub struct CalendarFormatter<'a> {
-    phase: PhaseFormatter<'a, I>,
+    phase: PhaseFormatter<'a, Q>,
 }
 
 impl<'a> CalendarFormatter<'a> {
@@ -72,7 +72,7 @@ impl Formatter<Calendar> for CalendarFormatter<'_> {
 }
 
 pub struct ClockFormatter<'a> {
-    phase: PhaseFormatter<'a, I>,
+    phase: PhaseFormatter<'a, Q>,
 }
 
 impl<'a> ClockFormatter<'a> {
@@ -1,22 +1,28 @@
-use std::sync::LazyLock;
+use core::fmt::{self, Display};
 
 use derive_more::Constructor;
 use mixed_point::{
-    BoundMixedPhase, IdentityPeriodRepresentation, Mixed, NamedPhase, Phase, PhaseLegend, SimpleCycle, SimpleMixed,
+    BalancedMixed, BalancedPoint, BalancedWithLegend, BoundMixedPhase, Mixed, MixedPointLegend, NamedPhase, Phase,
+    PhaseLegend, SimpleCycle, SimpleMixed, WideningPeriodRepresentation,
 };
-use numburs::{pos, HasZero, Integer, LowerBoundedRep, Positive};
+use numburs::{HasZero, Integer};
 
 use super::units::{Day, Edge, Edges};
-use crate::{base::I, temporal::standard::Days};
+use crate::{
+    base::{I, Q},
+    temporal::standard::Days,
+};
 
-pub type ClockType = SimpleMixed<IdentityPeriodRepresentation<I>, SimpleCycle<I>, 5>;
+pub type ClockType = SimpleMixed<WideningPeriodRepresentation<Q, I>, SimpleCycle<I>, 5>;
 pub type ClockPoint = BoundMixedPhase<'static, 5, ClockType>;
 
-pub static CLOCK: LazyLock<ClockType> =
-    LazyLock::new(|| SimpleMixed::from_simple_factors([pos!(2), pos!(64), pos!(64), pos!(8), pos!(16)]));
+pub const CLOCK: ClockType = ClockType::from_sizes([2, 64, 64, 8, 16]);
+
+pub const CLOCK_LEGEND: PhaseLegend<'static, 5> = PhaseLegend::new(["edge", "event", "moment", "beat", "rhythm"]);
+
+pub const BALANCED_CLOCK: BalancedMixed<WideningPeriodRepresentation<Q, I>, 5> = BalancedMixed::new(CLOCK);
 
-pub static CLOCK_LEGEND: LazyLock<PhaseLegend<'static, 5>> =
-    LazyLock::new(|| PhaseLegend::new(["edge", "event", "moment", "beat", "rhythm"]));
+pub const RELATIVE_CLOCK_LEGEND: MixedPointLegend<'static, 5> = MixedPointLegend::new("day", CLOCK_LEGEND);
 
 #[derive(Debug, PartialEq, Eq, Constructor, Clone)]
 pub struct Clock(pub ClockPoint);
@@ -27,6 +33,10 @@ pub struct ClockDuration {
     pub clock: Clock,
 }
 
+// A duration in signed clock digits, displayed as in "-1 beat +3 moment"
+#[derive(Debug, PartialEq, Eq, Constructor, Clone, Copy)]
+pub struct RelativeClock(pub BalancedPoint<I, 5>);
+
 #[derive(Debug, PartialEq, Eq, Constructor, Clone)]
 pub struct ClockWithDay {
     pub day: Day,
@@ -34,11 +44,11 @@ pub struct ClockWithDay {
 }
 
 impl Clock {
-    pub fn from_phase(phase: Phase<I, 5>) -> Option<Self> {
+    pub fn from_phase(phase: Phase<Q, 5>) -> Option<Self> {
         CLOCK.point(Integer::ZERO, phase).map(|b| Clock(b.phase()))
     }
 
-    pub fn name(&self) -> NamedPhase<'static, I, 5> {
+    pub fn name(&self) -> NamedPhase<'static, Q, 5> {
         CLOCK_LEGEND.name(self.0.phase)
     }
 }
@@ -50,6 +60,27 @@ impl From<Edges> for ClockDuration {
     }
 }
 
+impl From<Edges> for RelativeClock {
+    fn from(edges: Edges) -> Self {
+        Self(BALANCED_CLOCK.wind(edges.into()))
+    }
+}
+
+impl From<ClockDuration> for RelativeClock {
+    fn from(duration: ClockDuration) -> Self {
+        let point = CLOCK
+            .point(duration.days.0, duration.clock.0.phase)
+            .expect("clock phase is bound");
+        Self(BALANCED_CLOCK.balance(&point.point))
+    }
+}
+
+impl Display for RelativeClock {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        BalancedWithLegend::new(&self.0, &RELATIVE_CLOCK_LEGEND).fmt(f)
+    }
+}
+
 impl From<Edge> for ClockWithDay {
     fn from(edge: Edge) -> Self {
         let ClockDuration { days, clock } = ClockDuration::from(Edges::from(edge));
@@ -59,19 +90,45 @@ impl From<Edge> for ClockWithDay {
 
 #[cfg(test)]
 mod test {
-    use numburs::{int, integral::testing::*, Integer, Natural};
+    use std::mem::size_of;
 
-    use super::{Clock, ClockDuration};
-    use crate::{base::I, system_n::units::Edges, temporal::standard::Days};
+    use mixed_point::MixedPoint;
+    use numburs::{int, integral::testing::*, nat, Integer};
 
-    fn cons_days_clock(days: Integer<I>, phase: [Natural<I>; 5]) -> ClockDuration {
+    use super::{Clock, ClockDuration, RelativeClock};
+    use crate::{
+        base::{I, Q},
+        system_n::units::Edges,
+        temporal::standard::Days,
+    };
+
+    fn cons_days_clock(days: Integer<I>, phase: [Q; 5]) -> ClockDuration {
+        let phase = phase.map(|digit| nat!(digit));
         ClockDuration::new(Days::new(days), Clock::from_phase(phase).expect("Improper phase"))
     }
 
+    #[test]
+    fn narrow_digits() {
+        assert_eq!(16, size_of::<MixedPoint<Q, 5, Integer<I>>>());
+    }
+
     #[test]
     fn calendar() {
-        assert_eq!(cons_days_clock(I0, [N0, N0, N0, N0, N0]), Edges::new(I0).into());
-        assert_eq!(cons_days_clock(I0, [N1, N0, N0, N0, N0]), Edges::new(I1).into());
-        assert_eq!(cons_days_clock(I0, [N1, N6, N1, N0, N0]), Edges::new(int!(141)).into());
+        assert_eq!(cons_days_clock(I0, [0, 0, 0, 0, 0]), Edges::new(I0).into());
+        assert_eq!(cons_days_clock(I0, [1, 0, 0, 0, 0]), Edges::new(I1).into());
+        assert_eq!(cons_days_clock(I0, [1, 6, 1, 0, 0]), Edges::new(int!(141)).into());
+    }
+
+    #[test]
+    fn relative_clock() {
+        let edges = |total: i64| Edges::new(int!(total));
+        assert_eq!("0", RelativeClock::from(edges(0)).to_string());
+        assert_eq!(
+            "-1 beat +3 moment",
+            RelativeClock::from(edges(-8192 + 3 * 128)).to_string()
+        );
+        assert_eq!("-1 day +1 edge", RelativeClock::from(edges(-(1 << 20) + 1)).to_string());
+        let duration = ClockDuration::from(edges(-8192 + 3 * 128));
+        assert_eq!(RelativeClock::from(edges(-8192 + 3 * 128)), duration.into());
     }
 }
@@ -1,39 +1,54 @@
-use std::sync::LazyLock;
-
 use derive_more::{Constructor, From, Into};
 use mixed_point::{
-    BoundMixedPoint, Filter, IdentityPeriodRepresentation, LimitedCycle, Mixed, MixedPointLegend, Period, SimpleCycle,
-    SimpleFilter, SimpleMixed,
+    BoundMixedPoint, CheckedMixed, CycleKind, Filter, IdentityPeriodRepresentation, Intercalation, IntercalationRule,
+    Mixed, MixedPointLegend, OverflowError, Period, SimpleCycle, SimpleFilter, SimpleMixed,
 };
-use numburs::{pos, LowerBoundedRep, Positive};
+use numburs::{int, pos, Integer};
 
 use super::units::{Day, Year, YearDay};
 use crate::base::I;
 
 pub type DepthType = SimpleMixed<IdentityPeriodRepresentation<I>, SimpleCycle<I>, 3>;
-pub type DepthDaysType = SimpleFilter<IdentityPeriodRepresentation<I>, LimitedCycle<I>, 3>;
+pub type DepthDaysType = SimpleFilter<IdentityPeriodRepresentation<I>, CycleKind<I>, 3>;
 pub type DepthPoint = BoundMixedPoint<'static, 3, DepthType>;
-
-pub static DEPTH_YEARS: LazyLock<DepthType> =
-    LazyLock::new(|| SimpleMixed::from_simple_factors([pos!(8), pos!(2), pos!(16)]));
-
-pub static DEPTH_LEGEND: LazyLock<MixedPointLegend<'static, 3>> =
-    LazyLock::new(|| MixedPointLegend::with_names("aeon", ["unade", "octade", "hexade"]));
-
-pub static DEPTH_DAYS: LazyLock<DepthDaysType> = LazyLock::new(|| {
-    SimpleFilter::new(
-        Period::new(pos!(93502)),
-        [
-            LimitedCycle::new(pos!(5844), pos!(15)),
-            LimitedCycle::new(pos!(2922), pos!(1)),
-            LimitedCycle::new(pos!(365), pos!(7)),
-        ],
-    )
-});
+pub type DepthIntercalationType = Intercalation<I, 3, 2>;
+
+// Every octade ends with two extra days, except the last one of the aeon
+pub const DEPTH_INTERCALATION: DepthIntercalationType = Intercalation::new(
+    pos!(const I: 365),
+    [pos!(const I: 8), pos!(const I: 2), pos!(const I: 16)],
+    [
+        IntercalationRule::new(pos!(const I: 8), int!(2)),
+        IntercalationRule::new(pos!(const I: 256), int!(-2)),
+    ],
+);
+
+pub const DEPTH_YEARS: DepthType = DepthType::from_sizes([8, 2, 16]);
+
+pub const DEPTH_LEGEND: MixedPointLegend<'static, 3> =
+    MixedPointLegend::with_names("aeon", ["unade", "octade", "hexade"]);
+
+// Compiled from DEPTH_INTERCALATION, which the tests check
+pub const DEPTH_DAYS: DepthDaysType = DepthDaysType::from_kinds(
+    Period::new(pos!(const I: 93502)),
+    [
+        CycleKind::simple(pos!(const I: 5844)),
+        CycleKind::simple(pos!(const I: 2922)),
+        CycleKind::limited(pos!(const I: 365), pos!(const I: 7)),
+    ],
+);
 
 #[derive(Debug, PartialEq, Eq, Constructor, Into, From, Clone)]
 pub struct Depth(pub(crate) DepthPoint);
 
+impl Depth {
+    // Every year in range has a depth, but a depth read from elsewhere may
+    // lie beyond the last year
+    pub fn try_year(&self) -> Result<Year, OverflowError> {
+        DEPTH_YEARS.try_unwind(&self.0.point).map(Year::from)
+    }
+}
+
 impl From<Year> for Depth {
     fn from(year: Year) -> Self {
         Self(DEPTH_YEARS.wind(year.into()))
@@ -57,7 +72,7 @@ impl From<Day> for DepthWithDay {
     fn from(day: Day) -> Self {
         let point = DEPTH_DAYS.wind(day.into());
         Self::new(
-            Depth::new(BoundMixedPoint::new(&*DEPTH_YEARS, point.point.point)),
+            Depth::new(BoundMixedPoint::new(&DEPTH_YEARS, point.point.point)),
             YearDay::new(point.point.remainder),
         )
     }
@@ -65,23 +80,55 @@ impl From<Day> for DepthWithDay {
 
 #[cfg(test)]
 mod test {
-    use mixed_point::{BoundMixedPoint, MixedPoint};
-    use numburs::{int, integral::testing::*, nat, Integer, LowerBoundedRep, Natural};
+    use mixed_point::{
+        BoundMixedPoint, Coverage, Filter, IdentityPeriodRepresentation, LimitedCycle, MixedPoint, OverflowError,
+        Period, SimpleFilter,
+    };
+    use numburs::{int, integral::testing::*, nat, pos, Integer, Natural};
 
-    use super::{Depth, DepthWithDay, DEPTH_YEARS};
+    use super::{Depth, DepthDaysType, DepthType, DepthWithDay, DEPTH_DAYS, DEPTH_INTERCALATION, DEPTH_YEARS};
     use crate::{
         base::I,
         system_n::units::{Day, Year, YearDay},
     };
 
     fn cons_depth(aeons: Integer<I>, phase: [Natural<I>; 3]) -> Depth {
-        Depth::new(BoundMixedPoint::new(&*DEPTH_YEARS, MixedPoint::new(aeons, phase)))
+        Depth::new(BoundMixedPoint::new(&DEPTH_YEARS, MixedPoint::new(aeons, phase)))
     }
 
     fn cons_depth_with_day(aeons: Integer<I>, phase: [Natural<I>; 3], day: Natural<I>) -> DepthWithDay {
         DepthWithDay::new(cons_depth(aeons, phase), YearDay::new(day))
     }
 
+    #[test]
+    fn depth_intercalation() {
+        let filter: DepthDaysType = DEPTH_INTERCALATION.filter().expect("Depth intercalation is a filter");
+        assert_eq!(DEPTH_DAYS, filter);
+        let mixed: DepthType = DEPTH_INTERCALATION.mixed();
+        assert_eq!(DEPTH_YEARS, mixed);
+    }
+
+    // The definition DEPTH_DAYS had before it was compiled from the intercalation
+    #[test]
+    fn depth_days_unchanged() {
+        let limited = |size: I, limit: I| LimitedCycle::new(pos!(size), pos!(limit));
+        let original = SimpleFilter::<IdentityPeriodRepresentation<I>, _, 3>::new(
+            Period::new(pos!(93502)),
+            [limited(5844, 15), limited(2922, 1), limited(365, 7)],
+        );
+        for day in 0..93502 {
+            let point = original.wind(int!(day)).point;
+            assert_eq!(point, DEPTH_DAYS.wind(int!(day)).point);
+            assert_eq!(original.unwind(&point), DEPTH_DAYS.unwind(&point));
+        }
+    }
+
+    #[test]
+    fn depth_consistent() {
+        assert!(DEPTH_YEARS.validate(Coverage::Exhaustive).is_valid());
+        assert!(DEPTH_DAYS.validate(Coverage::Exhaustive).is_valid());
+    }
+
     #[test]
     fn depth_years() {
         assert_eq!(cons_depth(I0, [N0, N0, N0]), Year::new(I0).into());
@@ -96,6 +143,16 @@ mod test {
         assert_eq!(Year::new(-I1), (&cons_depth(-I1, [N7, N1, nat!(15)])).into());
     }
 
+    #[test]
+    fn years_out_of_range() {
+        let last = Depth::from(Year::new(int!(I::MAX)));
+        assert_eq!(Ok(Year::new(int!(I::MAX))), last.try_year());
+        let first = Depth::from(Year::new(int!(I::MIN)));
+        assert_eq!(Ok(Year::new(int!(I::MIN))), first.try_year());
+        let beyond = cons_depth(int!(I::MAX / 256 + 1), [N0, N0, N0]);
+        assert_eq!(Err(OverflowError::Unwind), beyond.try_year());
+    }
+
     #[test]
     fn depth_day() {
         let i364 = int!(364);
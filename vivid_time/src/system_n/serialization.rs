@@ -1,24 +1,28 @@
-use mixed_point::{MixedPoint, PhaseWithLegend};
+use mixed_point::{MixedPointSchema, MixedPointWithLegend, PhaseWithLegend};
 use numburs::ConstrainedRep;
 use serde::{
+    de::DeserializeSeed,
     ser::{SerializeStruct, Serializer},
-    Serialize,
+    Deserialize, Deserializer, Serialize,
 };
 
 use super::{
     calendar::{Calendar, CALENDAR_LEGEND},
     clock::{Clock, CLOCK_LEGEND},
-    depth::{Depth, DEPTH_LEGEND},
+    depth::{Depth, DEPTH_LEGEND, DEPTH_YEARS},
     units::{EdgeFraction, Year},
 };
 
 impl Serialize for Depth {
     fn serialize<S: Serializer>(&self, s: S) -> Result<S::Ok, S::Error> {
-        let MixedPoint { cycle, phase } = self.0.point;
-        let mut state = s.serialize_struct("Depth", 4)?;
-        state.serialize_field(DEPTH_LEGEND.cycle, &cycle.out())?;
-        state.serialize_field("phase", &PhaseWithLegend::new(phase, &DEPTH_LEGEND.phase, "DepthPhase"))?;
-        state.end()
+        MixedPointWithLegend::new(self.0.clone(), &DEPTH_LEGEND).serialize(s)
+    }
+}
+
+impl<'de> Deserialize<'de> for Depth {
+    fn deserialize<D: Deserializer<'de>>(d: D) -> Result<Self, D::Error> {
+        let read = MixedPointSchema::new(&DEPTH_YEARS, &DEPTH_LEGEND).deserialize(d)?;
+        Ok(Depth::new(read.point))
     }
 }
 
@@ -56,3 +60,19 @@ impl Serialize for EdgeFraction {
         s.serialize_newtype_struct("EdgeFraction", &self.0.out())
     }
 }
+
+#[cfg(test)]
+mod test {
+    use numburs::{int, Integer};
+
+    use crate::system_n::{depth::Depth, units::Year};
+
+    #[test]
+    fn depth_round_trip() {
+        let depth: Depth = Year::new(int!(603)).into();
+        let json = serde_json::to_string(&depth).unwrap();
+        assert_eq!(r#"{"aeon":2,"phase":{"hexade":5,"octade":1,"unade":3}}"#, json);
+        assert_eq!(depth, serde_json::from_str(&json).unwrap());
+        assert!(serde_json::from_str::<Depth>(r#"{"aeon":2,"phase":{"hexade":16,"octade":1,"unade":3}}"#).is_err());
+    }
+}